@@ -1,13 +1,69 @@
 //! High-level wrapper for BLS12-381 arithmetic using `blst`.
+//!
+//! Builds without `std` (but with `alloc`) when the default `std` feature is disabled.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod constants;
+#[cfg(feature = "arbitrary")]
+mod strategy;
 #[cfg(test)]
 mod tests;
 
 pub use constants::curve_order;
 
+use alloc::vec;
+use alloc::vec::Vec;
 use blst::{blst_fp12, blst_fr, blst_scalar};
 use paste::paste;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Errors when decoding field elements and points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input is not valid hex.
+    InvalidHex,
+    /// The input does not have the expected number of bytes.
+    InvalidLength { expected: usize, actual: usize },
+    /// The value is not less than the modulus `r`.
+    NotInField,
+    /// The bytes do not encode a point on the curve.
+    InvalidPoint,
+    /// The point is on the curve but not in the prime-order sub-group.
+    NotInSubgroup,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidHex => write!(f, "invalid hex"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "expected {} bytes but got {}", expected, actual)
+            }
+            Self::NotInField => write!(f, "value is not less than the modulus"),
+            Self::InvalidPoint => write!(f, "bytes do not encode a point on the curve"),
+            Self::NotInSubgroup => write!(f, "point is not in the prime-order sub-group"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Decode `input` as hex, with an optional `0x` prefix.
+fn decode_hex(input: &str, expected: usize) -> Result<Vec<u8>, DecodeError> {
+    let input = input.strip_prefix("0x").unwrap_or(input);
+    let bytes = hex::decode(input).map_err(|_| DecodeError::InvalidHex)?;
+    if bytes.len() != expected {
+        return Err(DecodeError::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes)
+}
 
 /// Field sub-group element.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
@@ -16,15 +72,80 @@ pub struct Fr {
 }
 
 impl Fr {
+    /// Return the additive identity.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Return the multiplicative identity.
+    pub fn one() -> Self {
+        Self::from_u64(1)
+    }
+
     pub fn from_u64(value: u64) -> Self {
         let mut point = Self::default();
-        let input = vec![value, 0, 0, 0];
+        let input = [value, 0, 0, 0];
         unsafe {
             blst::blst_fr_from_uint64(&mut point.element, input.as_ptr());
         }
         point
     }
 
+    /// Construct an element from its canonical big-endian encoding.
+    /// Returns `None` if the value is not less than the modulus `r`.
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Option<Self> {
+        let mut scalar = blst::blst_scalar::default();
+        unsafe {
+            blst::blst_scalar_from_bendian(&mut scalar, bytes.as_ptr());
+        }
+        Self::from_scalar_wiped(&mut scalar)
+    }
+
+    /// Convert `scalar` into an element, or `None` if it is not less than `r`.
+    /// `scalar` is wiped either way, as it may hold a secret such as a setup trapdoor.
+    fn from_scalar_wiped(scalar: &mut blst_scalar) -> Option<Self> {
+        let mut point = Self::default();
+        let valid = unsafe { blst::blst_scalar_fr_check(scalar) };
+        if valid {
+            unsafe {
+                blst::blst_fr_from_scalar(&mut point.element, scalar);
+            }
+        }
+        scalar.b.zeroize();
+        if valid {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
+    /// Return the canonical big-endian encoding of this element.
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut scalar = blst::blst_scalar::default();
+        let mut bytes = [0u8; 32];
+        unsafe {
+            blst::blst_scalar_from_fr(&mut scalar, &self.element);
+            blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &scalar);
+        }
+        bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Return the multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let mut result = blst::blst_fr::default();
+        unsafe {
+            blst::blst_fr_eucl_inverse(&mut result, &self.element);
+        }
+        Some(Self { element: result })
+    }
+
     pub fn from_raw(element: blst_fr) -> Self {
         Self { element }
     }
@@ -38,7 +159,32 @@ impl Fr {
     }
 }
 
-impl std::ops::Add for Fr {
+/// Formats as `0x` followed by the hex of the big-endian encoding.
+impl core::fmt::Display for Fr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "0x{}", hex::encode(self.to_bytes_be()))
+    }
+}
+
+/// Parses the output of `Display`; the `0x` prefix is optional.
+impl core::str::FromStr for Fr {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_hex(s, 32)?;
+        let mut buffer = [0u8; 32];
+        buffer.copy_from_slice(&bytes);
+        Self::from_bytes_be(&buffer).ok_or(DecodeError::NotInField)
+    }
+}
+
+impl Zeroize for Fr {
+    fn zeroize(&mut self) {
+        self.element.l.zeroize();
+    }
+}
+
+impl core::ops::Add for Fr {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -50,13 +196,13 @@ impl std::ops::Add for Fr {
     }
 }
 
-impl std::ops::AddAssign for Fr {
+impl core::ops::AddAssign for Fr {
     fn add_assign(&mut self, other: Self) {
         *self = *self + other
     }
 }
 
-impl std::ops::Neg for Fr {
+impl core::ops::Neg for Fr {
     type Output = Self;
 
     fn neg(self) -> Self {
@@ -69,7 +215,7 @@ impl std::ops::Neg for Fr {
     }
 }
 
-impl std::ops::Sub for Fr {
+impl core::ops::Sub for Fr {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -77,7 +223,13 @@ impl std::ops::Sub for Fr {
     }
 }
 
-impl std::ops::Mul for Fr {
+impl core::ops::SubAssign for Fr {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other
+    }
+}
+
+impl core::ops::Mul for Fr {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
@@ -89,13 +241,13 @@ impl std::ops::Mul for Fr {
     }
 }
 
-impl std::ops::MulAssign for Fr {
+impl core::ops::MulAssign for Fr {
     fn mul_assign(&mut self, other: Self) {
         *self = *self * other
     }
 }
 
-impl std::ops::Div for Fr {
+impl core::ops::Div for Fr {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
@@ -116,7 +268,7 @@ pub struct Fp12 {
     element: blst_fp12,
 }
 
-impl std::ops::Mul for Fp12 {
+impl core::ops::Mul for Fp12 {
     type Output = Fp12;
 
     fn mul(mut self, rhs: Fp12) -> Fp12 {
@@ -164,6 +316,21 @@ impl Scalar {
     }
 }
 
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.value.b.zeroize();
+    }
+}
+
+/// Scalars are commonly derived from secret values, so they are wiped when dropped.
+impl Drop for Scalar {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Scalar {}
+
 impl From<Fr> for Scalar {
     fn from(x: Fr) -> Self {
         let mut scalar = Self::default();
@@ -178,12 +345,22 @@ macro_rules! define_curve_struct {
     ($struct_name:ident, $blst_name:ident, $group_name:ident, $compressed_bytes:expr) => {
         paste! {
             #[doc = "Point on the curve sub-group " $group_name "."]
-            #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+            #[derive(Debug, Default, Copy, Clone)]
             pub struct $struct_name {
                 point: blst::[<blst_ $blst_name>],
             }
         }
 
+        /// Points are compared as group elements, independent of their projective representation.
+        impl PartialEq for $struct_name {
+            fn eq(&self, other: &Self) -> bool {
+                let is_equal = paste! { blst::[<blst_ $blst_name _is_equal>] };
+                unsafe { is_equal(&self.point, &other.point) }
+            }
+        }
+
+        impl Eq for $struct_name {}
+
         paste! {
             #[doc = "Affine encoding of a point on " $group_name "."]
             #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
@@ -216,6 +393,23 @@ macro_rules! define_curve_struct {
                 Self { point }
             }
 
+            /// Convert `points` to affine coordinates in-place, sharing a single field inversion.
+            pub fn batch_normalize(points: &mut [Self]) {
+                if points.is_empty() {
+                    return;
+                }
+                let pointers = points.iter().map(|p| &p.point as *const _).collect::<Vec<_>>();
+                let mut affine = vec![paste! { blst::[<blst_ $blst_name _affine>]::default() }; points.len()];
+                paste! {
+                    unsafe {
+                        blst::[<blst_ $blst_name s_to_affine>](affine.as_mut_ptr(), pointers.as_ptr(), points.len());
+                        for (point, affine) in points.iter_mut().zip(affine.iter()) {
+                            blst::[<blst_ $blst_name _from_affine>](&mut point.point, affine);
+                        }
+                    }
+                }
+            }
+
             pub fn compress(&self) -> Vec<u8> {
                 let mut compressed_point = vec![0; $compressed_bytes];
                 let compress = paste! { blst::[<blst_ $blst_name _compress>] };
@@ -224,14 +418,49 @@ macro_rules! define_curve_struct {
                 }
                 compressed_point
             }
+
+            /// Inverse of `compress`.
+            /// Fails unless `bytes` encodes a point in the prime-order sub-group.
+            pub fn decompress(bytes: &[u8]) -> Result<Self, DecodeError> {
+                if bytes.len() != $compressed_bytes {
+                    return Err(DecodeError::InvalidLength {
+                        expected: $compressed_bytes,
+                        actual: bytes.len(),
+                    });
+                }
+                let mut affine = paste! { blst::[<blst_ $blst_name _affine>]::default() };
+                let mut point = Self::default();
+                paste! {
+                    unsafe {
+                        let result = blst::[<blst_ $blst_name _uncompress>](&mut affine, bytes.as_ptr());
+                        if result != blst::BLST_ERROR::BLST_SUCCESS {
+                            return Err(DecodeError::InvalidPoint);
+                        }
+                        if !blst::[<blst_ $blst_name _affine_in_ $group_name:lower>](&affine) {
+                            return Err(DecodeError::NotInSubgroup);
+                        }
+                        blst::[<blst_ $blst_name _from_affine>](&mut point.point, &affine);
+                    }
+                }
+                Ok(point)
+            }
         }
 
-        impl std::fmt::Display for $struct_name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 write!(f, "0x{}", hex::encode(self.compress()))
             }
         }
 
+        /// Parses the output of `Display`; the `0x` prefix is optional.
+        impl core::str::FromStr for $struct_name {
+            type Err = DecodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::decompress(&decode_hex(s, $compressed_bytes)?)
+            }
+        }
+
         impl $struct_name {
             /// Negate this point in-place.
             pub fn negate(&mut self) {
@@ -244,7 +473,7 @@ macro_rules! define_curve_struct {
         }
 
         /// Unary negation.
-        impl std::ops::Neg for $struct_name {
+        impl core::ops::Neg for $struct_name {
             type Output = Self;
 
             fn neg(mut self) -> Self {
@@ -253,12 +482,12 @@ macro_rules! define_curve_struct {
             }
         }
 
-        /// Point addition.
-        impl std::ops::Add for $struct_name {
+        /// Point addition, including the doubling and identity cases.
+        impl core::ops::Add for $struct_name {
             type Output = Self;
 
             fn add(mut self, rhs: $struct_name) -> $struct_name {
-                let add = paste! { blst::[<blst_ $blst_name _add>] };
+                let add = paste! { blst::[<blst_ $blst_name _add_or_double>] };
                 unsafe {
                     add(&mut self.point, &self.point, &rhs.point);
                 }
@@ -267,7 +496,7 @@ macro_rules! define_curve_struct {
         }
 
         /// Scalar multiplication.
-        impl std::ops::Mul<$struct_name> for Scalar {
+        impl core::ops::Mul<$struct_name> for Scalar {
             type Output = $struct_name;
 
             fn mul(self, mut rhs: $struct_name) -> Self::Output {
@@ -285,7 +514,7 @@ macro_rules! define_curve_struct {
         }
 
         /// Scalar multiplication for [<$struct_name>].
-        impl std::ops::Mul<$struct_name> for Fr {
+        impl core::ops::Mul<$struct_name> for Fr {
             type Output = $struct_name;
 
             fn mul(self, rhs: $struct_name) -> Self::Output {
@@ -0,0 +1,101 @@
+//! Generators of field elements and points for fuzzing (`arbitrary`) and property testing (`proptest`).
+
+use crate::{Fr, P1, P2};
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+
+/// Reduce a big-endian integer of any length modulo `r`.
+fn fr_from_be_bytes_reduced(bytes: &[u8]) -> Fr {
+    let mut scalar = blst::blst_scalar::default();
+    let mut point = Fr::default();
+    unsafe {
+        blst::blst_scalar_from_be_bytes(&mut scalar, bytes.as_ptr(), bytes.len());
+        blst::blst_fr_from_scalar(&mut point.element, &scalar);
+    }
+    point
+}
+
+impl<'a> Arbitrary<'a> for Fr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        // NOTE: twice the width of `r` so that the reduction is close to uniform.
+        let bytes = <[u8; 64]>::arbitrary(u)?;
+        Ok(fr_from_be_bytes_reduced(&bytes))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 64]>::size_hint(depth)
+    }
+}
+
+impl proptest::arbitrary::Arbitrary for Fr {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        // Mix in small values, which shrink well and exercise edge cases like zero and one.
+        prop_oneof![
+            any::<u64>().prop_map(Fr::from_u64),
+            any::<([u8; 32], [u8; 32])>().prop_map(|(high, low)| {
+                let mut bytes = [0u8; 64];
+                bytes[..32].copy_from_slice(&high);
+                bytes[32..].copy_from_slice(&low);
+                fr_from_be_bytes_reduced(&bytes)
+            }),
+        ]
+        .boxed()
+    }
+}
+
+macro_rules! impl_point_strategy {
+    ($point:ident) => {
+        /// Points are sampled as random multiples of the generator.
+        impl<'a> Arbitrary<'a> for $point {
+            fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+                Ok(Fr::arbitrary(u)? * $point::generator())
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <Fr as Arbitrary>::size_hint(depth)
+            }
+        }
+
+        impl proptest::arbitrary::Arbitrary for $point {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<Fr>().prop_map(|x| x * $point::generator()).boxed()
+            }
+        }
+    };
+}
+
+impl_point_strategy!(P1);
+impl_point_strategy!(P2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arbitrary_from_bytes() {
+        let bytes = [0xffu8; 128];
+        let mut u = Unstructured::new(&bytes);
+        let x = Fr::arbitrary(&mut u).unwrap();
+        let y = Fr::arbitrary(&mut u).unwrap();
+        assert_eq!(x, y);
+        assert_eq!(Fr::from_bytes_be(&x.to_bytes_be()), Some(x));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        /// Decoding checks that scalars are canonical and that points are in the sub-group.
+        #[test]
+        fn generated_values_are_valid(x in any::<Fr>(), p in any::<P1>(), q in any::<P2>()) {
+            prop_assert_eq!(Fr::from_bytes_be(&x.to_bytes_be()), Some(x));
+            prop_assert_eq!(P1::decompress(&p.compress()), Ok(p));
+            prop_assert_eq!(P2::decompress(&q.compress()), Ok(q));
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use alloc::string::ToString;
 
 #[test]
 fn two_times_three_pairing() {
@@ -20,6 +21,41 @@ fn invalid_pairing() {
     ));
 }
 
+#[test]
+fn can_double() {
+    let g1 = P1::generator();
+    assert_eq!((g1 + g1).compress(), (Fr::from_u64(2) * g1).compress());
+    assert_eq!((g1 + P1::default()).compress(), g1.compress());
+    let g2 = P2::generator();
+    assert_eq!((g2 + g2).compress(), (Fr::from_u64(2) * g2).compress());
+}
+
+#[test]
+fn points_compare_as_group_elements() {
+    // NOTE: the same point, once in projective and once in affine coordinates (`z = 1`)
+    let sum = P1::generator() + P1::generator();
+    let mut affine = blst::blst_p1_affine::default();
+    let mut normalized = P1::default();
+    unsafe {
+        blst::blst_p1_to_affine(&mut affine, &sum.point);
+        blst::blst_p1_from_affine(&mut normalized.point, &affine);
+    }
+    assert_ne!(sum.point.z.l, normalized.point.z.l);
+    assert_eq!(sum, normalized);
+    assert_ne!(sum, P1::generator());
+
+    let sum = P2::generator() + P2::generator();
+    let mut affine = blst::blst_p2_affine::default();
+    let mut normalized = P2::default();
+    unsafe {
+        blst::blst_p2_to_affine(&mut affine, &sum.point);
+        blst::blst_p2_from_affine(&mut normalized.point, &affine);
+    }
+    assert_ne!(sum.point.z.fp[0].l, normalized.point.z.fp[0].l);
+    assert_eq!(sum, normalized);
+    assert_ne!(sum, P2::generator());
+}
+
 #[test]
 fn can_add() {
     let x = Fr::from_u64(1001);
@@ -58,3 +94,116 @@ fn can_divide() {
     let result = x / y;
     assert_eq!(result.as_u64(), 100);
 }
+
+#[test]
+fn can_invert() {
+    let x = Fr::from_u64(200);
+    assert_eq!(x * x.inverse().unwrap(), Fr::one());
+    assert!(Fr::zero().inverse().is_none());
+}
+
+#[test]
+fn bytes_round_trip() {
+    let x = -Fr::from_u64(3);
+    assert_eq!(Fr::from_bytes_be(&x.to_bytes_be()), Some(x));
+    assert_eq!(Fr::from_u64(258).to_bytes_be()[30..], [1, 2]);
+
+    let modulus = curve_order().to_bytes_be();
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&modulus);
+    assert!(Fr::from_bytes_be(&bytes).is_none());
+}
+
+#[test]
+fn can_zeroize() {
+    use zeroize::Zeroize;
+
+    let mut x = Fr::from_u64(200);
+    x.zeroize();
+    assert_eq!(x, Fr::zero());
+
+    let mut scalar = Scalar::from(Fr::from_u64(200));
+    scalar.zeroize();
+    assert_eq!(scalar, Scalar::default());
+}
+
+#[test]
+fn decoding_wipes_scalar() {
+    let x = -Fr::from_u64(200);
+    let mut scalar = Scalar::from(x).value.clone();
+    assert_eq!(Fr::from_scalar_wiped(&mut scalar), Some(x));
+    assert_eq!(scalar.b, [0u8; 32]);
+
+    // NOTE: `blst_scalar` holds little-endian bytes
+    let mut scalar = blst_scalar::default();
+    scalar.b.copy_from_slice(&curve_order().to_bytes_le());
+    assert_eq!(Fr::from_scalar_wiped(&mut scalar), None);
+    assert_eq!(scalar.b, [0u8; 32]);
+
+    assert_eq!(Fr::from_bytes_be(&x.to_bytes_be()), Some(x));
+}
+
+#[test]
+fn can_parse_field_element() {
+    let x = -Fr::from_u64(3);
+    let encoded = x.to_string();
+    assert!(encoded.starts_with("0x"));
+    assert_eq!(encoded.parse::<Fr>(), Ok(x));
+    assert_eq!(encoded[2..].parse::<Fr>(), Ok(x));
+
+    assert_eq!("0xzz".parse::<Fr>(), Err(DecodeError::InvalidHex));
+    assert_eq!(
+        "0x0102".parse::<Fr>(),
+        Err(DecodeError::InvalidLength {
+            expected: 32,
+            actual: 2
+        })
+    );
+    let modulus = hex::encode(curve_order().to_bytes_be());
+    assert_eq!(modulus.parse::<Fr>(), Err(DecodeError::NotInField));
+}
+
+#[test]
+fn can_parse_points() {
+    let p1 = Fr::from_u64(7) * P1::generator();
+    assert_eq!(p1.to_string().parse::<P1>(), Ok(p1));
+    assert_eq!(P1::default().to_string().parse::<P1>(), Ok(P1::default()));
+
+    let p2 = Fr::from_u64(7) * P2::generator();
+    assert_eq!(p2.to_string().parse::<P2>(), Ok(p2));
+    assert_eq!(hex::encode(p2.compress()).parse::<P2>(), Ok(p2));
+
+    // a `P1` encoding is too short for `P2`
+    assert_eq!(
+        p1.to_string().parse::<P2>(),
+        Err(DecodeError::InvalidLength {
+            expected: 96,
+            actual: 48
+        })
+    );
+
+    // x = 0 is not on the curve
+    let mut bytes = vec![0u8; 48];
+    bytes[0] = 0x80;
+    assert_eq!(P1::decompress(&bytes), Err(DecodeError::InvalidPoint));
+}
+
+#[test]
+fn can_batch_normalize() {
+    let g1 = P1::generator();
+    let mut p1s = vec![g1, g1 + g1, P1::default(), Fr::from_u64(5) * g1];
+    let expected = p1s.clone();
+    P1::batch_normalize(&mut p1s);
+    assert_eq!(p1s, expected);
+    for (point, original) in p1s.iter().zip(expected.iter()) {
+        assert_eq!(point.compress(), original.compress());
+    }
+
+    let g2 = P2::generator();
+    let mut p2s = vec![g2 + g2, Fr::from_u64(9) * g2];
+    let expected = p2s.clone();
+    P2::batch_normalize(&mut p2s);
+    assert_eq!(p2s, expected);
+
+    P1::batch_normalize(&mut []);
+}
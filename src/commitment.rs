@@ -1,62 +1,50 @@
+use crate::engine::{Bls12_381, Field, Group, PairingEngine};
 use crate::polynomial;
 use crate::setup;
-use oblast::{verify_pairings, Fr, P1, P2};
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[derive(Debug)]
-pub struct Opening {
-    pub value: Fr,
-    pub proof: P1,
+pub struct Opening<E: PairingEngine = Bls12_381> {
+    pub value: E::Fr,
+    pub proof: E::G1,
 }
 
 #[derive(Debug)]
-pub struct Commitment<'a> {
-    element: P1,
-    polynomial: &'a polynomial::Polynomial,
-    setup: &'a setup::Setup,
+pub struct Commitment<'a, E: PairingEngine = Bls12_381> {
+    element: E::G1,
+    polynomial: Committed<'a, E::Fr>,
+    setup: &'a setup::Setup<E>,
 }
 
-fn compute_quotient(
-    dividend: &polynomial::Polynomial,
-    divisor: &polynomial::Polynomial,
-) -> polynomial::Polynomial {
-    let mut dividend = dividend.coefficients.clone();
-    let mut coefficients = vec![];
-
-    let mut dividend_pos = dividend.len() - 1;
-    let divisor_pos = divisor.coefficients.len() - 1;
-    let mut difference = dividend_pos as isize - divisor_pos as isize;
-
-    while difference >= 0 {
-        let term_quotient = dividend[dividend_pos] / divisor.coefficients[divisor_pos];
-        coefficients.push(term_quotient);
-
-        for i in (0..=divisor_pos).rev() {
-            let difference = difference as usize;
-            let x = divisor.coefficients[i];
-            let y = x * term_quotient;
-            let z = dividend[difference + i];
-            dividend[difference + i] = z - y;
-        }
-
-        dividend_pos -= 1;
-        difference -= 1;
-    }
-
-    coefficients.reverse();
-    polynomial::Polynomial { coefficients }
+/// The committed polynomial, in the form it was committed in.
+#[derive(Debug)]
+enum Committed<'a, F: Field> {
+    Dense(&'a polynomial::Polynomial<F>),
+    Sparse(&'a polynomial::SparsePolynomial<F>),
 }
 
-impl<'a> Commitment<'a> {
-    pub fn open_at(self: &Self, point: Fr) -> Opening {
-        let result = self.polynomial.evaluate_at(point);
+impl<'a, E: PairingEngine> Commitment<'a, E> {
+    pub fn open_at(&self, point: E::Fr) -> Opening<E> {
+        // NOTE: the quotient is dense either way, so a sparse polynomial is only densified here
+        let densified;
+        let dense = match self.polynomial {
+            Committed::Dense(polynomial) => polynomial,
+            Committed::Sparse(polynomial) => {
+                densified = polynomial.to_dense();
+                &densified
+            }
+        };
+        let result = dense.evaluate_at(point);
 
         // divisor `s - x` for `f(x) = y`
-        let divisor_coefficients = vec![-point, Fr::from_u64(1)];
+        let divisor_coefficients = vec![-point, E::Fr::one()];
         let divisor = polynomial::from_coefficients(divisor_coefficients.into_iter());
 
-        let quotient_polynomial = compute_quotient(self.polynomial, &divisor);
+        let (quotient_polynomial, _) = dense.div_rem(&divisor).expect("divisor is nonzero");
 
-        let commitment = create(&quotient_polynomial, self.setup);
+        let commitment = create(&quotient_polynomial, self.setup)
+            .expect("quotient has a lower degree than the committed polynomial");
 
         Opening {
             value: result,
@@ -65,46 +53,91 @@ impl<'a> Commitment<'a> {
     }
 }
 
-pub fn create<'a>(
-    polynomial: &'a polynomial::Polynomial,
-    setup: &'a setup::Setup,
-) -> Commitment<'a> {
-    let basis = &setup.in_g1;
+/// Commit to `polynomial` with the powers of the trapdoor in `setup`.
+/// Fails with [`polynomial::Error::DegreeTooLarge`] if `setup` is too small for the degree of
+/// `polynomial`.
+pub fn create<'a, E: PairingEngine>(
+    polynomial: &'a polynomial::Polynomial<E::Fr>,
+    setup: &'a setup::Setup<E>,
+) -> Result<Commitment<'a, E>, polynomial::Error> {
+    if let Some(degree) = polynomial.degree() {
+        check_fits_setup(degree, setup)?;
+    }
     let coefficients = &polynomial.coefficients;
+    let basis = &setup.in_g1[..coefficients.len()];
 
-    let mut result = P1::default();
-    for (coefficient, element) in coefficients.iter().zip(basis.iter()) {
-        let term = *coefficient * *element;
-        result = result + term;
-    }
+    let result = E::g1_msm(basis, coefficients)?;
 
-    Commitment {
+    Ok(Commitment {
         element: result,
-        polynomial,
+        polynomial: Committed::Dense(polynomial),
+        setup,
+    })
+}
+
+/// Like [`create`], but only touching the bases of `setup` for the nonzero terms of `polynomial`.
+/// Fails with [`polynomial::Error::DegreeTooLarge`] if `setup` is too small for the degree of
+/// `polynomial`.
+pub fn create_sparse<'a, E: PairingEngine>(
+    polynomial: &'a polynomial::SparsePolynomial<E::Fr>,
+    setup: &'a setup::Setup<E>,
+) -> Result<Commitment<'a, E>, polynomial::Error> {
+    if let Some(degree) = polynomial.degree() {
+        check_fits_setup(degree, setup)?;
+    }
+    let (bases, scalars): (Vec<_>, Vec<_>) = polynomial
+        .terms()
+        .iter()
+        .map(|(degree, coefficient)| (setup.in_g1[*degree], *coefficient))
+        .unzip();
+
+    Ok(Commitment {
+        element: E::g1_msm(&bases, &scalars)?,
+        polynomial: Committed::Sparse(polynomial),
         setup,
+    })
+}
+
+fn check_fits_setup<E: PairingEngine>(
+    degree: usize,
+    setup: &setup::Setup<E>,
+) -> Result<(), polynomial::Error> {
+    if degree >= setup.in_g1.len() {
+        return Err(polynomial::Error::DegreeTooLarge {
+            degree,
+            size: setup.in_g1.len(),
+        });
     }
+    Ok(())
 }
 
-impl Opening {
-    pub fn verify(&self, input: &Fr, commitment: &Commitment) -> bool {
+impl<E: PairingEngine> Opening<E> {
+    pub fn verify(&self, input: &E::Fr, commitment: &Commitment<E>) -> bool {
         // Compute [f(s) - y]_1 for LHS
-        let y_p1 = self.value * P1::generator();
+        let y_p1 = E::G1::generator().mul_scalar(&self.value);
         let commitment_minus_y = commitment.element + -y_p1;
 
         // Compute [s - z]_2 for RHS
-        let z_p2 = *input * P2::generator();
+        let z_p2 = E::G2::generator().mul_scalar(input);
         let s_minus_z = commitment.setup.in_g2 + -z_p2;
 
-        verify_pairings(commitment_minus_y, P2::generator(), self.proof, s_minus_z)
+        E::verify_pairings(
+            commitment_minus_y,
+            E::G2::generator(),
+            self.proof,
+            s_minus_z,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::setup;
-    use hex;
-    use std::convert::TryInto;
+    use crate::setup::{self, Zeroizing};
+    use crate::test_utils::engine_test;
+    use alloc::vec::Vec;
+    use core::convert::TryInto;
+    use oblast::{Fr, P1};
 
     #[test]
     fn test_opening() {
@@ -248,12 +281,12 @@ mod tests {
 
             let degree = coefficients.len();
 
-            let secret = secret.as_slice().try_into().unwrap();
-            let setup = setup::generate(secret, degree);
+            let secret: [u8; 32] = secret.as_slice().try_into().unwrap();
+            let setup = setup::generate(Zeroizing::new(secret), degree);
 
             let polynomial = polynomial::from_coefficients(coefficients.into_iter());
 
-            let commitment = create(&polynomial, &setup);
+            let commitment = create(&polynomial, &setup).unwrap();
 
             let opening = commitment.open_at(point);
 
@@ -261,48 +294,135 @@ mod tests {
             assert_eq!(opening.value.as_u64(), value);
 
             // does commitment match?
-            let commitment_serialization = commitment.element.compress();
-            let expected_commitment_serialization = hex::decode(expected_commitment_hex).unwrap();
-            assert_eq!(commitment_serialization, expected_commitment_serialization);
+            let expected_commitment = expected_commitment_hex.parse::<P1>().unwrap();
+            assert_eq!(commitment.element, expected_commitment);
 
             // does proof match?
-            let proof_serialization = opening.proof.compress();
-            let expected_proof_serialization = hex::decode(expected_proof_hex).unwrap();
-            assert_eq!(proof_serialization, expected_proof_serialization);
+            let expected_proof = expected_proof_hex.parse::<P1>().unwrap();
+            assert_eq!(opening.proof, expected_proof);
 
             // does the proof verify?
             assert!(opening.verify(&point, &commitment));
         }
     }
 
-    #[test]
-    fn test_verify_opening_identity_polynomial() {
+    fn verify_opening_identity_polynomial<E: PairingEngine>() {
         let secret = [11u8; 32];
         let degree = 1;
-        let setup = setup::generate(&secret, degree);
+        let setup = setup::Setup::<E>::generate(Zeroizing::new(secret), degree);
 
         // Using f(x) = x, so [f(s)] = [s]
         let commitment_element = &setup.in_g1[1];
         // Use the same point for input & output
-        let point = Fr::from_u64(2);
+        let point = E::Fr::from_u64(2);
 
         let polynomial =
-            &polynomial::from_coefficients(vec![Fr::from_u64(0), Fr::from_u64(1)].into_iter());
+            &polynomial::from_coefficients(vec![E::Fr::zero(), E::Fr::one()].into_iter());
 
         let commitment = Commitment {
             element: *commitment_element,
-            polynomial: polynomial,
+            polynomial: Committed::Dense(polynomial),
             setup: &setup,
         };
 
         // Therefore the quotient polynomial is q(x) = 1
-        let proof = P1::generator();
+        let proof = E::G1::generator();
 
-        let opening = Opening {
+        let opening = Opening::<E> {
             value: point,
             proof,
         };
 
         assert!(opening.verify(&point, &commitment));
     }
+
+    engine_test!(
+        test_verify_opening_identity_polynomial,
+        verify_opening_identity_polynomial
+    );
+
+    fn open_and_verify<E: PairingEngine>() {
+        let setup = setup::Setup::<E>::generate(Zeroizing::new([7u8; 32]), 8);
+        let coefficients = vec![3, 1, 4, 1, 5, 9, 2, 6, 5]
+            .into_iter()
+            .map(E::Fr::from_u64)
+            .collect::<Vec<_>>();
+        let polynomial = polynomial::from_coefficients(coefficients.into_iter());
+        let commitment = create(&polynomial, &setup).unwrap();
+
+        let point = E::Fr::from_u64(15);
+        let opening = commitment.open_at(point);
+        assert_eq!(opening.value, polynomial.evaluate_at(point));
+        assert!(opening.verify(&point, &commitment));
+
+        // a proof for a different point must not verify
+        let other_point = E::Fr::from_u64(16);
+        assert!(!opening.verify(&other_point, &commitment));
+
+        // nor must a wrong claimed value
+        let forged = Opening::<E> {
+            value: opening.value + E::Fr::one(),
+            proof: opening.proof,
+        };
+        assert!(!forged.verify(&point, &commitment));
+    }
+
+    fn open_zero_polynomial<E: PairingEngine>() {
+        let setup = setup::Setup::<E>::generate(Zeroizing::new([7u8; 32]), 2);
+        let polynomial = polynomial::Polynomial::zero();
+        let commitment = create(&polynomial, &setup).unwrap();
+
+        let point = E::Fr::from_u64(15);
+        let opening = commitment.open_at(point);
+        assert_eq!(opening.value, E::Fr::zero());
+        assert_eq!(opening.proof, E::G1::identity());
+        assert!(opening.verify(&point, &commitment));
+    }
+
+    engine_test!(test_open_zero_polynomial, open_zero_polynomial);
+
+    engine_test!(test_open_and_verify, open_and_verify);
+
+    fn sparse_commitment<E: PairingEngine>() {
+        let setup = setup::Setup::<E>::generate(Zeroizing::new([7u8; 32]), 16);
+        let sparse = polynomial::SparsePolynomial::new(vec![
+            (0, -E::Fr::one()),
+            (5, E::Fr::from_u64(3)),
+            (16, E::Fr::one()),
+        ]);
+        let dense = sparse.to_dense();
+        let commitment = create_sparse(&sparse, &setup).unwrap();
+        assert_eq!(commitment.element, create(&dense, &setup).unwrap().element);
+
+        let point = E::Fr::from_u64(15);
+        let opening = commitment.open_at(point);
+        assert_eq!(opening.value, sparse.evaluate_at(point));
+        assert!(opening.verify(&point, &commitment));
+
+        let zero = polynomial::SparsePolynomial::zero();
+        let zero = create_sparse(&zero, &setup).unwrap();
+        assert_eq!(zero.element, E::G1::identity());
+    }
+
+    engine_test!(test_sparse_commitment, sparse_commitment);
+
+    #[test]
+    fn test_sparse_commitment_exceeding_setup() {
+        let setup = setup::generate(Zeroizing::new([7u8; 32]), 4);
+        let sparse = polynomial::SparsePolynomial::new(vec![(5, Fr::one())]);
+        assert_eq!(
+            create_sparse(&sparse, &setup).unwrap_err(),
+            polynomial::Error::DegreeTooLarge { degree: 5, size: 5 }
+        );
+    }
+
+    #[test]
+    fn test_commitment_exceeding_setup() {
+        let setup = setup::generate(Zeroizing::new([7u8; 32]), 4);
+        let polynomial = polynomial::Polynomial::monomial(5);
+        assert_eq!(
+            create(&polynomial, &setup).unwrap_err(),
+            polynomial::Error::DegreeTooLarge { degree: 5, size: 5 }
+        );
+    }
 }
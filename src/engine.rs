@@ -0,0 +1,302 @@
+//! Abstractions over the pairing-friendly curve backing the commitment scheme.
+//!
+//! `Bls12_381` (via `oblast`) is always available and is the default engine
+//! throughout the crate. `Bn254` (via arkworks) is available behind the `bn254` feature.
+
+mod bls12_381;
+#[cfg(feature = "bn254")]
+mod bn254;
+
+pub use bls12_381::Bls12_381;
+#[cfg(feature = "bn254")]
+pub use bn254::Bn254;
+
+use crate::polynomial::Error;
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use rand::RngCore;
+use zeroize::Zeroize;
+
+/// Prime-order scalar field of a pairing engine.
+///
+/// Elements must support `Zeroize` as they are used to hold secrets, e.g. the trapdoor of a `Setup`.
+pub trait Field:
+    Copy
+    + Send
+    + Sync
+    + Debug
+    + Default
+    + Eq
+    + Zeroize
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Neg<Output = Self>
+    + Div<Output = Self>
+{
+    /// The modulus `r`, as little-endian 64-bit limbs.
+    const MODULUS: [u64; 4];
+
+    /// `2^TWO_ADICITY` is the largest power of two dividing `r - 1`.
+    const TWO_ADICITY: u32;
+
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn from_u64(value: u64) -> Self;
+
+    /// Construct an element from its canonical big-endian encoding.
+    /// Returns `None` if the value is not less than the modulus.
+    fn from_bytes_be(bytes: &[u8; 32]) -> Option<Self>;
+
+    /// Return the canonical big-endian encoding of this element.
+    fn to_bytes_be(&self) -> [u8; 32];
+
+    /// Return the multiplicative inverse, or `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+
+    /// Return a primitive `2^TWO_ADICITY`-th root of unity.
+    fn two_adic_root_of_unity() -> Self;
+
+    /// Return a generator of the multiplicative group of the field.
+    fn multiplicative_generator() -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Sample a uniformly random element.
+    fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        // NOTE: reduce 512 random bits, twice the width of the modulus, so that the bias is negligible.
+        let mut limbs = [0u64; 8];
+        limbs.iter_mut().for_each(|limb| *limb = rng.next_u64());
+        Self::from_limbs_reduced(&limbs)
+    }
+
+    /// Reduce the integer given by big-endian 64-bit `limbs`, of any length, modulo `r`.
+    fn from_limbs_reduced(limbs: &[u64]) -> Self {
+        let shift = Self::from_u64(1 << 32).pow(2);
+        limbs.iter().fold(Self::zero(), |acc, limb| {
+            acc * shift + Self::from_u64(*limb)
+        })
+    }
+
+    fn pow(&self, mut exponent: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Like [`Field::pow`], with `exponent` given as little-endian 64-bit limbs.
+    fn pow_limbs(&self, exponent: &[u64]) -> Self {
+        exponent.iter().rev().fold(Self::one(), |result, limb| {
+            let shifted = (0..64).fold(result, |x, _| x * x);
+            shifted * self.pow(*limb)
+        })
+    }
+}
+
+/// Prime-order group whose scalars are elements of `F`.
+pub trait Group<F: Field>:
+    Copy + Send + Sync + Debug + Eq + Add<Output = Self> + Neg<Output = Self>
+{
+    fn identity() -> Self;
+
+    /// Return the distinguished generator point.
+    fn generator() -> Self;
+
+    fn mul_scalar(&self, scalar: &F) -> Self;
+
+    /// Convert `points` to affine coordinates in-place, sharing a single field inversion.
+    fn batch_normalize(points: &mut [Self]);
+}
+
+/// A pairing `e: G1 x G2 -> GT` over groups of prime order `|Fr|`.
+pub trait PairingEngine: Copy + Debug + Eq + 'static {
+    type Fr: Field;
+    type G1: Group<Self::Fr>;
+    type G2: Group<Self::Fr>;
+
+    /// Compute `sum(scalars[i] * bases[i])`.
+    /// Fails with [`Error::InvalidLength`] if the inputs differ in length.
+    fn g1_msm(bases: &[Self::G1], scalars: &[Self::Fr]) -> Result<Self::G1, Error> {
+        check_msm_lengths(bases, scalars)?;
+        Ok(bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(Self::G1::identity(), |sum, (base, scalar)| {
+                sum + base.mul_scalar(scalar)
+            }))
+    }
+
+    /// Check that `e(x1, x2) = e(y1, y2)`.
+    fn verify_pairings(x1: Self::G1, x2: Self::G2, y1: Self::G1, y2: Self::G2) -> bool;
+}
+
+/// Check that an MSM has one base per scalar.
+fn check_msm_lengths<G, F>(bases: &[G], scalars: &[F]) -> Result<(), Error> {
+    if bases.len() != scalars.len() {
+        return Err(Error::InvalidLength {
+            expected: bases.len(),
+            actual: scalars.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn field_encoding<E: PairingEngine>() {
+        let x = -E::Fr::from_u64(3);
+        assert_eq!(E::Fr::from_bytes_be(&x.to_bytes_be()), Some(x));
+        assert_eq!(E::Fr::from_u64(258).to_bytes_be()[30..], [1, 2]);
+        assert!(E::Fr::from_bytes_be(&[0xff; 32]).is_none());
+    }
+
+    fn field_inverse<E: PairingEngine>() {
+        let x = E::Fr::from_u64(200);
+        assert_eq!(x * x.inverse().unwrap(), E::Fr::one());
+        assert!(E::Fr::zero().inverse().is_none());
+        assert_eq!(x.pow(3), x * x * x);
+    }
+
+    fn pairing<E: PairingEngine>() {
+        let scalar = |x| E::Fr::from_u64(x);
+        let g1 = E::G1::generator();
+        let g2 = E::G2::generator();
+        assert!(E::verify_pairings(
+            g1.mul_scalar(&scalar(2)),
+            g2.mul_scalar(&scalar(3)),
+            g1.mul_scalar(&scalar(3)),
+            g2.mul_scalar(&scalar(2)),
+        ));
+        assert!(!E::verify_pairings(
+            g1.mul_scalar(&scalar(2)),
+            g2.mul_scalar(&scalar(4)),
+            g1.mul_scalar(&scalar(3)),
+            g2.mul_scalar(&scalar(2)),
+        ));
+    }
+
+    fn msm<E: PairingEngine>() {
+        let g1 = E::G1::generator();
+        let bases = vec![g1, g1.mul_scalar(&E::Fr::from_u64(2))];
+        let scalars = vec![E::Fr::from_u64(5), E::Fr::from_u64(7)];
+        let expected = g1.mul_scalar(&E::Fr::from_u64(19));
+        assert_eq!(E::g1_msm(&bases, &scalars), Ok(expected));
+        assert_eq!(E::g1_msm(&[], &[]), Ok(E::G1::identity()));
+        assert_eq!(
+            E::g1_msm(&bases, &scalars[..1]),
+            Err(Error::InvalidLength {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    fn root_of_unity<E: PairingEngine>() {
+        let mut root = E::Fr::two_adic_root_of_unity();
+        for _ in 1..E::Fr::TWO_ADICITY {
+            root *= root;
+            assert_ne!(root, E::Fr::one());
+        }
+        assert_eq!(root, -E::Fr::one());
+    }
+
+    fn modulus_and_generator<E: PairingEngine>() {
+        let mut modulus_minus_one = E::Fr::MODULUS;
+        modulus_minus_one[0] -= 1;
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.rchunks_mut(8).zip(modulus_minus_one.iter()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert_eq!(E::Fr::from_bytes_be(&bytes), Some(-E::Fr::one()));
+
+        // the modulus reduces to zero, whatever the number of leading zero limbs
+        let mut limbs = E::Fr::MODULUS;
+        limbs.reverse();
+        assert_eq!(E::Fr::from_limbs_reduced(&limbs), E::Fr::zero());
+        assert_eq!(
+            E::Fr::from_limbs_reduced(&[0, 0, limbs[0], limbs[1], limbs[2], limbs[3] + 5]),
+            E::Fr::from_u64(5)
+        );
+
+        // `g^((r - 1) / 2) = -1` as the generator is not a square, and `g^((r - 1) / 2^s)`
+        // is the primitive `2^s`-th root of unity
+        let g = E::Fr::multiplicative_generator();
+        let shift_right = |limbs: [u64; 4], bits: u32| {
+            let mut result = [0u64; 4];
+            for i in 0..4 {
+                result[i] = limbs[i] >> bits;
+                if i + 1 < 4 && bits > 0 {
+                    result[i] |= limbs[i + 1] << (64 - bits);
+                }
+            }
+            result
+        };
+        assert_eq!(
+            g.pow_limbs(&shift_right(modulus_minus_one, 1)),
+            -E::Fr::one()
+        );
+        assert_eq!(
+            g.pow_limbs(&shift_right(modulus_minus_one, E::Fr::TWO_ADICITY)),
+            E::Fr::two_adic_root_of_unity()
+        );
+        assert_eq!(g.pow_limbs(&modulus_minus_one), E::Fr::one());
+        assert_eq!(g.pow_limbs(&[5, 0]), g.pow(5));
+    }
+
+    fn batch_normalize<E: PairingEngine>() {
+        let g1 = E::G1::generator();
+        let mut points = vec![
+            g1,
+            g1 + g1,
+            E::G1::identity(),
+            g1.mul_scalar(&E::Fr::from_u64(5)),
+        ];
+        let expected = points.clone();
+        E::G1::batch_normalize(&mut points);
+        assert_eq!(points, expected);
+
+        let g2 = E::G2::generator();
+        let mut points = vec![g2 + g2, g2.mul_scalar(&E::Fr::from_u64(9))];
+        let expected = points.clone();
+        E::G2::batch_normalize(&mut points);
+        assert_eq!(points, expected);
+    }
+
+    fn run_all<E: PairingEngine>() {
+        batch_normalize::<E>();
+        field_encoding::<E>();
+        field_inverse::<E>();
+        root_of_unity::<E>();
+        modulus_and_generator::<E>();
+        pairing::<E>();
+        msm::<E>();
+    }
+
+    #[test]
+    fn bls12_381() {
+        run_all::<Bls12_381>();
+    }
+
+    #[cfg(feature = "bn254")]
+    #[test]
+    fn bn254() {
+        run_all::<Bn254>();
+    }
+}
@@ -0,0 +1,89 @@
+use super::{Field, Group, PairingEngine};
+use oblast::{Fr, P1, P2};
+
+/// The BLS12-381 curve, backed by `oblast`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Bls12_381;
+
+/// `7^((r - 1) / 2^32)`, as `7` generates the multiplicative group of `Fr`.
+const TWO_ADIC_ROOT_OF_UNITY: [u8; 32] = [
+    0x16, 0xa2, 0xa1, 0x9e, 0xdf, 0xe8, 0x1f, 0x20, 0xd0, 0x9b, 0x68, 0x19, 0x22, 0xc8, 0x13, 0xb4,
+    0xb6, 0x36, 0x83, 0x50, 0x8c, 0x22, 0x80, 0xb9, 0x38, 0x29, 0x97, 0x1f, 0x43, 0x9f, 0x0d, 0x2b,
+];
+
+impl Field for Fr {
+    const MODULUS: [u64; 4] = [
+        0xffff_ffff_0000_0001,
+        0x53bd_a402_fffe_5bfe,
+        0x3339_d808_09a1_d805,
+        0x73ed_a753_299d_7d48,
+    ];
+    const TWO_ADICITY: u32 = 32;
+
+    fn zero() -> Self {
+        Fr::zero()
+    }
+
+    fn one() -> Self {
+        Fr::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fr::from_u64(value)
+    }
+
+    fn from_bytes_be(bytes: &[u8; 32]) -> Option<Self> {
+        Fr::from_bytes_be(bytes)
+    }
+
+    fn to_bytes_be(&self) -> [u8; 32] {
+        Fr::to_bytes_be(self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fr::inverse(self)
+    }
+
+    fn two_adic_root_of_unity() -> Self {
+        Fr::from_bytes_be(&TWO_ADIC_ROOT_OF_UNITY).expect("constant is in the field")
+    }
+
+    fn multiplicative_generator() -> Self {
+        Fr::from_u64(7)
+    }
+}
+
+macro_rules! impl_group {
+    ($point:ident) => {
+        impl Group<Fr> for $point {
+            fn identity() -> Self {
+                $point::default()
+            }
+
+            fn generator() -> Self {
+                $point::generator()
+            }
+
+            fn mul_scalar(&self, scalar: &Fr) -> Self {
+                *scalar * *self
+            }
+
+            fn batch_normalize(points: &mut [Self]) {
+                $point::batch_normalize(points)
+            }
+        }
+    };
+}
+
+impl_group!(P1);
+impl_group!(P2);
+
+impl PairingEngine for Bls12_381 {
+    type Fr = Fr;
+    type G1 = P1;
+    type G2 = P2;
+
+    fn verify_pairings(x1: P1, x2: P2, y1: P1, y2: P2) -> bool {
+        oblast::verify_pairings(x1, x2, y1, y2)
+    }
+}
@@ -0,0 +1,119 @@
+use super::{Error, Field, Group, PairingEngine};
+use ark_bn254::{g1, g2, Fr};
+use ark_ec::pairing::Pairing;
+use ark_ec::short_weierstrass::Projective;
+use ark_ec::{CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, BigInteger256, FftField, One, PrimeField, Zero};
+use zeroize::Zeroize;
+
+// NOTE: spelled out rather than via `ark_bn254::G{1,2}Projective` so that
+// coherence can tell the two `Group` impls apart.
+type G1Projective = Projective<g1::Config>;
+type G2Projective = Projective<g2::Config>;
+
+/// The BN254 (alt_bn128) curve, backed by arkworks.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Bn254;
+
+impl Field for Fr {
+    const MODULUS: [u64; 4] = <Fr as PrimeField>::MODULUS.0;
+    const TWO_ADICITY: u32 = <Fr as FftField>::TWO_ADICITY;
+
+    fn zero() -> Self {
+        Zero::zero()
+    }
+
+    fn one() -> Self {
+        One::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Fr::from(value)
+    }
+
+    fn from_bytes_be(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut buffer = [0u8; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+            buffer.copy_from_slice(chunk);
+            *limb = u64::from_be_bytes(buffer);
+        }
+        let result = Fr::from_bigint(BigInteger256::new(limbs));
+        // NOTE: `bytes` may encode a secret, so do not leave copies behind.
+        limbs.zeroize();
+        buffer.zeroize();
+        result
+    }
+
+    fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&self.into_bigint().to_bytes_be());
+        bytes
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        ark_ff::Field::inverse(self)
+    }
+
+    fn two_adic_root_of_unity() -> Self {
+        <Fr as FftField>::TWO_ADIC_ROOT_OF_UNITY
+    }
+
+    fn multiplicative_generator() -> Self {
+        <Fr as FftField>::GENERATOR
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(self)
+    }
+}
+
+macro_rules! impl_group {
+    ($point:ident) => {
+        impl Group<Fr> for $point {
+            fn identity() -> Self {
+                Zero::zero()
+            }
+
+            fn generator() -> Self {
+                ark_ec::Group::generator()
+            }
+
+            fn mul_scalar(&self, scalar: &Fr) -> Self {
+                *self * scalar
+            }
+
+            fn batch_normalize(points: &mut [Self]) {
+                let affine = $point::normalize_batch(points);
+                for (point, affine) in points.iter_mut().zip(affine) {
+                    *point = affine.into();
+                }
+            }
+        }
+    };
+}
+
+impl_group!(G1Projective);
+impl_group!(G2Projective);
+
+impl PairingEngine for Bn254 {
+    type Fr = Fr;
+    type G1 = G1Projective;
+    type G2 = G2Projective;
+
+    fn g1_msm(bases: &[G1Projective], scalars: &[Fr]) -> Result<G1Projective, Error> {
+        super::check_msm_lengths(bases, scalars)?;
+        let bases = G1Projective::normalize_batch(bases);
+        Ok(G1Projective::msm_unchecked(&bases, scalars))
+    }
+
+    fn verify_pairings(
+        x1: G1Projective,
+        x2: G2Projective,
+        y1: G1Projective,
+        y2: G2Projective,
+    ) -> bool {
+        // Negate one of the inputs to avoid an exponentiation.
+        ark_bn254::Bn254::multi_pairing([-x1, y1], [x2, y2]).is_zero()
+    }
+}
@@ -1,29 +1,43 @@
+//! KZG polynomial commitments.
+//!
+//! Builds without `std` (but with `alloc`) when the default `std` feature is disabled;
+//! everything except `setup::generate_with_random_secret` remains available.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod commitment;
+pub mod engine;
 pub mod polynomial;
 pub mod setup;
-
+#[cfg(feature = "arbitrary")]
+pub mod strategy;
 #[cfg(test)]
+mod test_utils;
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
-    use oblast::Fr;
+    use crate::test_utils::engine_test;
+    use engine::{Field, PairingEngine};
 
-    #[test]
-    fn end_to_end() {
+    fn run_end_to_end<E: PairingEngine>() {
         let coefficients = vec![1, 2, 3, 1, 1, 17, 32]
             .into_iter()
-            .map(oblast::Fr::from_u64)
+            .map(E::Fr::from_u64)
             .collect::<Vec<_>>();
         let degree = coefficients.len();
 
-        let setup = setup::generate_with_random_secret(degree);
+        let setup = setup::Setup::<E>::generate_with_random_secret(degree);
 
         let polynomial = polynomial::from_coefficients(coefficients.into_iter());
 
         // prover sends commitment
-        let commitment = commitment::create(&polynomial, &setup);
+        let commitment = commitment::create(&polynomial, &setup).unwrap();
 
         // verifier sends over a point
-        let point = Fr::from_u64(1234);
+        let point = E::Fr::from_u64(1234);
 
         // prover "opens" at that point
         let opening = commitment.open_at(point);
@@ -32,4 +46,6 @@ mod tests {
         let valid = opening.verify(&point, &commitment);
         assert!(valid);
     }
+
+    engine_test!(end_to_end, run_end_to_end);
 }
@@ -1,46 +1,377 @@
+mod arithmetic;
+mod bivariate;
+mod division;
+mod domain;
+mod evaluation;
+mod interpolation;
+mod lagrange;
+mod multilinear;
+mod serialization;
+mod six_step;
+mod sparse;
+mod transform;
+mod tree;
+mod vanishing;
+
+pub use arithmetic::NTT_MULTIPLICATION_THRESHOLD;
+pub use bivariate::BivariatePolynomial;
+pub use division::FAST_DIVISION_THRESHOLD;
+pub use domain::EvaluationDomain;
+pub use evaluation::{evaluate_batch, MULTIPOINT_EVALUATION_THRESHOLD};
+pub use interpolation::INTERPOLATION_THRESHOLD;
+pub use lagrange::LagrangePolynomial;
+pub use multilinear::MultilinearPolynomial;
+pub use serialization::BYTES_PER_FIELD_ELEMENT;
+pub use six_step::SIX_STEP_FFT_THRESHOLD;
+pub use sparse::SparsePolynomial;
+pub use vanishing::VanishingPolynomial;
+
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
 use oblast::Fr;
+use rand::RngCore;
+
+/// Errors from operations on polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The divisor is the zero polynomial.
+    DivisionByZero,
+    /// No evaluation domain of this size exists in the field.
+    InvalidDomainSize(usize),
+    /// Extending a domain of size `size` by `blowup` overflows `usize`.
+    DomainSizeOverflow { size: usize, blowup: usize },
+    /// An input has the wrong length, e.g. a number of evaluations which does not match the
+    /// size of the domain, or a number of bytes which does not match an encoding.
+    InvalidLength { expected: usize, actual: usize },
+    /// The polynomial does not fit in a domain, or a setup, of this size.
+    DegreeTooLarge { degree: usize, size: usize },
+    /// Two points to interpolate share an x-coordinate.
+    DuplicatePoint,
+    /// The encoding of the field element at `index` is not less than the modulus.
+    NonCanonicalFieldElement { index: usize },
+    /// The encoding of a polynomial ends with a zero coefficient.
+    NonCanonicalPolynomial,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DivisionByZero => write!(f, "division by the zero polynomial"),
+            Self::InvalidDomainSize(size) => write!(f, "no evaluation domain of size {}", size),
+            Self::DomainSizeOverflow { size, blowup } => write!(
+                f,
+                "extending a domain of size {} by {} overflows",
+                size, blowup
+            ),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "expected length {} but got {}", expected, actual)
+            }
+            Self::DegreeTooLarge { degree, size } => write!(
+                f,
+                "polynomial of degree {} does not fit in size {}",
+                degree, size
+            ),
+            Self::DuplicatePoint => write!(f, "points must have distinct x-coordinates"),
+            Self::NonCanonicalFieldElement { index } => {
+                write!(f, "field element {} is not canonically encoded", index)
+            }
+            Self::NonCanonicalPolynomial => {
+                write!(
+                    f,
+                    "polynomial is encoded with a high-order zero coefficient"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
 
-#[derive(Debug)]
-pub struct Polynomial {
+/// A univariate polynomial in coefficient form.
+///
+/// Polynomials are kept normalized, i.e. without high-order zero coefficients,
+/// so the zero polynomial has no coefficients at all. Callers mutating
+/// `coefficients` directly should restore this with `truncate_leading_zeros`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial<F: Field = Fr> {
     // NOTE: low-order coefficients are first in the vector
-    pub coefficients: Vec<Fr>,
+    pub coefficients: Vec<F>,
 }
 
-impl Polynomial {
-    pub fn evaluate_at(self: &Self, point: Fr) -> Fr {
-        let mut sum = self.coefficients[0].clone();
-        let mut powers = point.clone();
+impl<F: Field> Polynomial<F> {
+    /// Construct a polynomial from `coefficients`, dropping high-order zeros.
+    pub(crate) fn from_raw(coefficients: Vec<F>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.truncate_leading_zeros();
+        polynomial
+    }
 
-        for coefficient in self.coefficients.iter().skip(1) {
-            let term = *coefficient * powers;
-            sum += term;
-            powers *= point;
+    pub fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
         }
+    }
+
+    pub fn constant(value: F) -> Self {
+        Self::from_raw(vec![value])
+    }
+
+    /// Return `x^k`.
+    pub fn monomial(k: usize) -> Self {
+        Self::constant(F::one()).shift_degree(k)
+    }
+
+    /// Sample a polynomial of exactly the given `degree` with uniformly random coefficients.
+    pub fn random(degree: usize, rng: &mut impl RngCore) -> Self {
+        let mut coefficients = (0..degree).map(|_| F::random(rng)).collect::<Vec<_>>();
+        let leading = loop {
+            let coefficient = F::random(rng);
+            if !coefficient.is_zero() {
+                break coefficient;
+            }
+        };
+        coefficients.push(leading);
+        Self { coefficients }
+    }
+
+    /// Return the monic polynomial `prod(x - r_i)` with the given `roots`.
+    pub fn from_roots(roots: &[F]) -> Self {
+        Self::vanishing(roots)
+    }
+
+    /// Return the polynomial of degree less than `n` with the given evaluations over `domain`.
+    pub fn from_evaluations(
+        domain: &EvaluationDomain<F>,
+        evaluations: Vec<F>,
+    ) -> Result<Self, Error> {
+        Ok(LagrangePolynomial::new(evaluations, domain)?.to_polynomial())
+    }
+
+    /// Return the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        trimmed(&self.coefficients).len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.degree().is_none()
+    }
+
+    /// Remove high-order zero coefficients.
+    pub fn truncate_leading_zeros(&mut self) {
+        let length = trimmed(&self.coefficients).len();
+        self.coefficients.truncate(length);
+    }
 
-        sum
+    pub fn evaluate_at(&self, point: F) -> F {
+        // Horner's rule, from the highest-order coefficient down.
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |sum, coefficient| sum * point + *coefficient)
     }
 }
 
-pub fn from_coefficients(coefficients: impl Iterator<Item = Fr>) -> Polynomial {
-    Polynomial {
-        coefficients: coefficients.collect(),
+impl<F: Field> Default for Polynomial<F> {
+    fn default() -> Self {
+        Self::zero()
     }
 }
 
+impl<F: Field> From<Vec<F>> for Polynomial<F> {
+    /// Construct a (normalized) polynomial from its coefficients, lowest order first.
+    fn from(coefficients: Vec<F>) -> Self {
+        Self::from_raw(coefficients)
+    }
+}
+
+impl<F: Field> FromIterator<F> for Polynomial<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(coefficients: I) -> Self {
+        Self::from_raw(coefficients.into_iter().collect())
+    }
+}
+
+/// Return `coefficients` without high-order zeros.
+pub(crate) fn trimmed<F: Field>(coefficients: &[F]) -> &[F] {
+    let length = coefficients
+        .iter()
+        .rposition(|c| !c.is_zero())
+        .map_or(0, |i| i + 1);
+    &coefficients[..length]
+}
+
+/// Replace every element of `values` with its inverse, using a single field inversion.
+/// Panics if any element is zero.
+pub(crate) fn batch_inversion<F: Field>(values: &mut [F]) {
+    // Montgomery's trick: invert the product of all elements, then peel off one element at a time.
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = F::one();
+    for value in values.iter() {
+        prefix_products.push(product);
+        product *= *value;
+    }
+
+    let mut inverse = product.inverse().expect("values are nonzero");
+    for (value, prefix_product) in values.iter_mut().zip(prefix_products).rev() {
+        let next_inverse = inverse * *value;
+        *value = inverse * prefix_product;
+        inverse = next_inverse;
+    }
+}
+
+/// Return the first `count` powers `1, z, z^2, ...` of `z`.
+pub fn powers_of<F: Field>(z: F, count: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(count);
+    let mut power = F::one();
+    for _ in 0..count {
+        result.push(power);
+        power *= z;
+    }
+    result
+}
+
+/// Construct a (normalized) polynomial from its coefficients, lowest order first.
+pub fn from_coefficients<F: Field>(coefficients: impl Iterator<Item = F>) -> Polynomial<F> {
+    Polynomial::from_raw(coefficients.collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::{engine_test, polynomial};
+    use alloc::vec;
+    use rand::rngs::mock::StepRng;
 
-    #[test]
-    fn can_eval_polynomial() {
+    fn eval_polynomial<E: PairingEngine>() {
         let coefficients = vec![42, 1, 1, 0, 1]
             .into_iter()
-            .map(Fr::from_u64)
+            .map(E::Fr::from_u64)
             .collect::<Vec<_>>();
         let polynomial = from_coefficients(coefficients.into_iter());
-        let point = Fr::from_u64(2);
-        let result_in_fr = polynomial.evaluate_at(point);
-        let result = result_in_fr.as_u64();
-        assert_eq!(result, 64);
+        let point = E::Fr::from_u64(2);
+        let result = polynomial.evaluate_at(point);
+        assert_eq!(result, E::Fr::from_u64(64));
     }
+
+    engine_test!(can_eval_polynomial, eval_polynomial);
+
+    fn degree_and_normalization<E: PairingEngine>() {
+        let zero = Polynomial::<E::Fr>::zero();
+        assert!(zero.is_zero());
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero, Polynomial::default());
+        assert_eq!(zero, Polynomial::constant(E::Fr::zero()));
+        assert_eq!(zero, polynomial(&[0, 0, 0]));
+        assert_eq!(zero.evaluate_at(E::Fr::from_u64(3)), E::Fr::zero());
+
+        let constant = Polynomial::constant(E::Fr::from_u64(5));
+        assert_eq!(constant.degree(), Some(0));
+        assert_eq!(constant.evaluate_at(E::Fr::from_u64(3)), E::Fr::from_u64(5));
+
+        let p = polynomial::<E::Fr>(&[1, 2, 0, 0]);
+        assert_eq!(p.coefficients.len(), 2);
+        assert_eq!(p.degree(), Some(1));
+        assert!(!p.is_zero());
+
+        let mut q = p.clone();
+        q.coefficients.push(E::Fr::zero());
+        assert_eq!(q.degree(), Some(1));
+        q.truncate_leading_zeros();
+        assert_eq!(q, p);
+    }
+
+    engine_test!(can_track_degree, degree_and_normalization);
+
+    fn arithmetic<E: PairingEngine>() {
+        let a = polynomial::<E::Fr>(&[1, 2, 3]);
+        let b = polynomial::<E::Fr>(&[5, 7]);
+
+        assert_eq!(&a + &b, polynomial(&[6, 9, 3]));
+        assert_eq!(b.clone() + a.clone(), polynomial(&[6, 9, 3]));
+        assert_eq!(&a - &b, polynomial(&[0, 0, 3]) - polynomial(&[4, 5]));
+        assert_eq!(&b - &a + a.clone(), b);
+        assert_eq!(&a * &b, polynomial(&[5, 17, 29, 21]));
+        assert_eq!(&a * E::Fr::from_u64(2), polynomial(&[2, 4, 6]));
+        assert_eq!(-&a + a.clone(), polynomial(&[]));
+
+        // results are normalized
+        let c = polynomial::<E::Fr>(&[2, 2, 3]);
+        assert_eq!((&c - &a).coefficients, vec![E::Fr::one()]);
+        assert!((&a - &a.clone()).coefficients.is_empty());
+        assert!((&a * E::Fr::zero()).coefficients.is_empty());
+        assert!((&a * polynomial(&[])).coefficients.is_empty());
+        assert!((-polynomial::<E::Fr>(&[0, 0])).coefficients.is_empty());
+    }
+
+    engine_test!(can_do_arithmetic, arithmetic);
+
+    fn ntt_multiplication<E: PairingEngine>() {
+        let a = from_coefficients((0..100).map(|i| E::Fr::from_u64(i * i + 1)));
+        let b = from_coefficients((0..80).map(|i| E::Fr::from_u64(3 * i + 2)));
+        let expected = arithmetic::schoolbook_multiply(&a.coefficients, &b.coefficients);
+
+        let product = &a * &b;
+        assert_eq!(product.coefficients, expected);
+        assert_eq!(product, b * a);
+    }
+
+    engine_test!(can_multiply_with_ntt, ntt_multiplication);
+
+    fn constructors<E: PairingEngine>() {
+        let mut rng = StepRng::new(3, 0x9e37_79b9_7f4a_7c15);
+        for degree in [0, 1, 17] {
+            let p = Polynomial::<E::Fr>::random(degree, &mut rng);
+            assert_eq!(p.degree(), Some(degree));
+        }
+        assert_ne!(
+            Polynomial::<E::Fr>::random(4, &mut rng),
+            Polynomial::random(4, &mut rng)
+        );
+
+        assert_eq!(Polynomial::<E::Fr>::monomial(0), polynomial(&[1]));
+        assert_eq!(Polynomial::<E::Fr>::monomial(3), polynomial(&[0, 0, 0, 1]));
+
+        let roots = [E::Fr::from_u64(2), E::Fr::from_u64(3)];
+        // (x - 2)(x - 3) = x^2 - 5x + 6
+        let expected = from_coefficients(
+            vec![E::Fr::from_u64(6), -E::Fr::from_u64(5), E::Fr::one()].into_iter(),
+        );
+        assert_eq!(Polynomial::from_roots(&roots), expected);
+
+        let domain = EvaluationDomain::<E::Fr>::new(4).unwrap();
+        let evaluations = domain
+            .elements()
+            .into_iter()
+            .map(|x| expected.evaluate_at(x))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            Polynomial::from_evaluations(&domain, evaluations),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            Polynomial::from_evaluations(&domain, vec![E::Fr::one()]),
+            Err(Error::InvalidLength {
+                expected: 4,
+                actual: 1
+            })
+        );
+
+        let coefficients = vec![
+            E::Fr::from_u64(6),
+            -E::Fr::from_u64(5),
+            E::Fr::one(),
+            E::Fr::zero(),
+        ];
+        assert_eq!(Polynomial::from(coefficients.clone()), expected);
+        assert_eq!(
+            coefficients.into_iter().collect::<Polynomial<_>>(),
+            expected
+        );
+    }
+
+    engine_test!(test_constructors, constructors);
 }
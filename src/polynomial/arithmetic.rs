@@ -0,0 +1,157 @@
+//! Arithmetic operators on `Polynomial`.
+//!
+//! Binary operators are implemented for every combination of owned and borrowed operands
+//! and always return a normalized polynomial, i.e. without trailing zero coefficients.
+
+use super::{EvaluationDomain, Polynomial};
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Multiplication switches from schoolbook to the NTT once both operands have more coefficients than this.
+pub const NTT_MULTIPLICATION_THRESHOLD: usize = 64;
+
+fn add<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F> {
+    let (longer, shorter) = if a.coefficients.len() >= b.coefficients.len() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut coefficients = longer.coefficients.clone();
+    for (x, y) in coefficients.iter_mut().zip(shorter.coefficients.iter()) {
+        *x += *y;
+    }
+    Polynomial::from_raw(coefficients)
+}
+
+fn sub<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F> {
+    let mut coefficients = a.coefficients.clone();
+    if coefficients.len() < b.coefficients.len() {
+        coefficients.resize(b.coefficients.len(), F::zero());
+    }
+    for (x, y) in coefficients.iter_mut().zip(b.coefficients.iter()) {
+        *x -= *y;
+    }
+    Polynomial::from_raw(coefficients)
+}
+
+fn mul<F: Field>(a: &Polynomial<F>, b: &Polynomial<F>) -> Polynomial<F> {
+    let (a, b) = (&a.coefficients, &b.coefficients);
+    if a.is_empty() || b.is_empty() {
+        return Polynomial::zero();
+    }
+    if a.len().min(b.len()) > NTT_MULTIPLICATION_THRESHOLD {
+        if let Ok(domain) = EvaluationDomain::with_minimum_size(a.len() + b.len() - 1) {
+            return Polynomial::from_raw(ntt_multiply(a, b, &domain));
+        }
+    }
+    Polynomial::from_raw(schoolbook_multiply(a, b))
+}
+
+pub(crate) fn schoolbook_multiply<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut coefficients = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            coefficients[i + j] += *x * *y;
+        }
+    }
+    coefficients
+}
+
+/// Multiply via pointwise products of evaluations over `domain`, which must fit the product.
+fn ntt_multiply<F: Field>(a: &[F], b: &[F], domain: &EvaluationDomain<F>) -> Vec<F> {
+    let mut a_evaluations = a.to_vec();
+    let mut b_evaluations = b.to_vec();
+    domain.fft(&mut a_evaluations);
+    domain.fft(&mut b_evaluations);
+    for (x, y) in a_evaluations.iter_mut().zip(b_evaluations.iter()) {
+        *x *= *y;
+    }
+    domain.ifft(&mut a_evaluations);
+    a_evaluations
+}
+
+fn scale<F: Field>(a: &Polynomial<F>, scalar: F) -> Polynomial<F> {
+    let coefficients = a.coefficients.iter().map(|x| *x * scalar).collect();
+    Polynomial::from_raw(coefficients)
+}
+
+macro_rules! impl_binary_op {
+    ($op:ident, $method:ident, $function:ident) => {
+        impl<'a, 'b, F: Field> $op<&'b Polynomial<F>> for &'a Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: &'b Polynomial<F>) -> Polynomial<F> {
+                $function(self, rhs)
+            }
+        }
+
+        impl<'b, F: Field> $op<&'b Polynomial<F>> for Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: &'b Polynomial<F>) -> Polynomial<F> {
+                $function(&self, rhs)
+            }
+        }
+
+        impl<'a, F: Field> $op<Polynomial<F>> for &'a Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: Polynomial<F>) -> Polynomial<F> {
+                $function(self, &rhs)
+            }
+        }
+
+        impl<F: Field> $op<Polynomial<F>> for Polynomial<F> {
+            type Output = Polynomial<F>;
+
+            fn $method(self, rhs: Polynomial<F>) -> Polynomial<F> {
+                $function(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, add);
+impl_binary_op!(Sub, sub, sub);
+impl_binary_op!(Mul, mul, mul);
+
+/// Multiplication by a scalar.
+impl<F: Field> Mul<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: F) -> Polynomial<F> {
+        scale(self, rhs)
+    }
+}
+
+/// Multiplication by a scalar.
+impl<F: Field> Mul<F> for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: F) -> Polynomial<F> {
+        scale(&self, rhs)
+    }
+}
+
+impl<F: Field> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(self) -> Polynomial<F> {
+        let coefficients = self.coefficients.iter().map(|x| -*x).collect();
+        Polynomial::from_raw(coefficients)
+    }
+}
+
+impl<F: Field> Neg for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn neg(mut self) -> Polynomial<F> {
+        for x in self.coefficients.iter_mut() {
+            *x = -*x;
+        }
+        self.truncate_leading_zeros();
+        self
+    }
+}
@@ -0,0 +1,253 @@
+//! Bivariate polynomials in coefficient form, and their 2D FFTs over product domains.
+
+use super::{powers_of, Error, EvaluationDomain, Polynomial};
+use crate::engine::Field;
+use alloc::vec::Vec;
+use oblast::Fr;
+
+/// The polynomial `p(X, Y) = sum_{i, j} c_{i, j} X^i Y^j`, given by its coefficient matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BivariatePolynomial<F: Field = Fr> {
+    // NOTE: `coefficients[i][j]` is the coefficient of `X^i Y^j`, every row has the same length
+    coefficients: Vec<Vec<F>>,
+}
+
+impl<F: Field> BivariatePolynomial<F> {
+    /// Construct from the coefficient matrix, indexed by the degree in `X` then in `Y`.
+    /// Every row must have the same length.
+    pub fn new(coefficients: Vec<Vec<F>>) -> Result<Self, Error> {
+        let width = coefficients.first().map_or(0, Vec::len);
+        if let Some(row) = coefficients.iter().find(|row| row.len() != width) {
+            return Err(Error::InvalidLength {
+                expected: width,
+                actual: row.len(),
+            });
+        }
+        Ok(Self { coefficients })
+    }
+
+    /// Return the coefficient matrix, indexed by the degree in `X` then in `Y`.
+    pub fn coefficients(&self) -> &[Vec<F>] {
+        &self.coefficients
+    }
+
+    /// Return the number of rows and columns of the coefficient matrix, i.e. one more
+    /// than the largest representable degree in `X` and in `Y`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (
+            self.coefficients.len(),
+            self.coefficients.first().map_or(0, Vec::len),
+        )
+    }
+
+    pub fn evaluate_at(&self, x: F, y: F) -> F {
+        self.partial_evaluate_x(x).evaluate_at(y)
+    }
+
+    /// Return the univariate polynomial `p(x, Y)` in `Y`.
+    pub fn partial_evaluate_x(&self, x: F) -> Polynomial<F> {
+        let (rows, columns) = self.dimensions();
+        let mut result = alloc::vec![F::zero(); columns];
+        for (row, power) in self.coefficients.iter().zip(powers_of(x, rows)) {
+            for (coefficient, value) in result.iter_mut().zip(row) {
+                *coefficient += *value * power;
+            }
+        }
+        Polynomial::from(result)
+    }
+
+    /// Return the univariate polynomial `p(X, y)` in `X`.
+    pub fn partial_evaluate_y(&self, y: F) -> Polynomial<F> {
+        let powers = powers_of(y, self.dimensions().1);
+        self.coefficients
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&powers)
+                    .fold(F::zero(), |sum, (value, power)| sum + *value * *power)
+            })
+            .collect()
+    }
+
+    /// Evaluate over the product domain `H_X x H_Y`, so that `evaluations[a][b] = p(w_X^a, w_Y^b)`.
+    /// Panics if the coefficient matrix does not fit in the domains.
+    pub fn fft(
+        &self,
+        x_domain: &EvaluationDomain<F>,
+        y_domain: &EvaluationDomain<F>,
+    ) -> Vec<Vec<F>> {
+        let mut values = self.coefficients.clone();
+        transform_2d(&mut values, x_domain, y_domain, EvaluationDomain::fft);
+        values
+    }
+
+    /// Interpolate the evaluations over the product domain `H_X x H_Y`, laid out as returned by
+    /// [`BivariatePolynomial::fft`].
+    pub fn from_evaluations(
+        mut evaluations: Vec<Vec<F>>,
+        x_domain: &EvaluationDomain<F>,
+        y_domain: &EvaluationDomain<F>,
+    ) -> Result<Self, Error> {
+        if evaluations.len() != x_domain.size() {
+            return Err(Error::InvalidLength {
+                expected: x_domain.size(),
+                actual: evaluations.len(),
+            });
+        }
+        if let Some(row) = evaluations.iter().find(|row| row.len() != y_domain.size()) {
+            return Err(Error::InvalidLength {
+                expected: y_domain.size(),
+                actual: row.len(),
+            });
+        }
+        transform_2d(&mut evaluations, x_domain, y_domain, EvaluationDomain::ifft);
+        Ok(Self {
+            coefficients: evaluations,
+        })
+    }
+}
+
+/// Apply `transform` along `Y` to every row, then along `X` to every column, padding the
+/// matrix to the size of the domains.
+fn transform_2d<F: Field>(
+    values: &mut Vec<Vec<F>>,
+    x_domain: &EvaluationDomain<F>,
+    y_domain: &EvaluationDomain<F>,
+    transform: fn(&EvaluationDomain<F>, &mut Vec<F>),
+) {
+    assert!(
+        values.len() <= x_domain.size(),
+        "cannot fit {} rows in a domain of size {}",
+        values.len(),
+        x_domain.size()
+    );
+    values.resize(x_domain.size(), Vec::new());
+    for row in values.iter_mut() {
+        transform(y_domain, row);
+    }
+
+    let mut column = Vec::with_capacity(x_domain.size());
+    for j in 0..y_domain.size() {
+        column.clear();
+        column.extend(values.iter().map(|row| row[j]));
+        transform(x_domain, &mut column);
+        for (row, value) in values.iter_mut().zip(&column) {
+            row[j] = *value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+    use alloc::vec;
+
+    fn bivariate<F: Field>(rows: u64, columns: u64) -> BivariatePolynomial<F> {
+        BivariatePolynomial::new(
+            (0..rows)
+                .map(|i| {
+                    (0..columns)
+                        .map(|j| F::from_u64(i * 7 + j * j + 1))
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn evaluation<E: PairingEngine>() {
+        let p = bivariate::<E::Fr>(3, 4);
+        assert_eq!(p.dimensions(), (3, 4));
+        let (x, y) = (E::Fr::from_u64(5), E::Fr::from_u64(9));
+
+        let mut expected = E::Fr::zero();
+        for (i, row) in p.coefficients().iter().enumerate() {
+            for (j, coefficient) in row.iter().enumerate() {
+                expected += *coefficient * x.pow(i as u64) * y.pow(j as u64);
+            }
+        }
+        assert_eq!(p.evaluate_at(x, y), expected);
+
+        let in_y = p.partial_evaluate_x(x);
+        assert_eq!(in_y.degree(), Some(3));
+        assert_eq!(in_y.evaluate_at(y), expected);
+
+        let in_x = p.partial_evaluate_y(y);
+        assert_eq!(in_x.degree(), Some(2));
+        assert_eq!(in_x.evaluate_at(x), expected);
+
+        let empty = BivariatePolynomial::<E::Fr>::new(vec![]).unwrap();
+        assert_eq!(empty.evaluate_at(x, y), E::Fr::zero());
+        assert!(empty.partial_evaluate_y(y).is_zero());
+
+        assert_eq!(
+            BivariatePolynomial::new(vec![vec![E::Fr::one(); 2], vec![E::Fr::one(); 3]]),
+            Err(Error::InvalidLength {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    engine_test!(test_evaluation, evaluation);
+
+    fn fft<E: PairingEngine>() {
+        let p = bivariate::<E::Fr>(3, 5);
+        // a mixed-radix domain in `Y`
+        let x_domain = EvaluationDomain::<E::Fr>::new(4).unwrap();
+        let y_domain = EvaluationDomain::<E::Fr>::new(6).unwrap();
+
+        let evaluations = p.fft(&x_domain, &y_domain);
+        assert_eq!(evaluations.len(), 4);
+        for (a, row) in evaluations.iter().enumerate() {
+            assert_eq!(row.len(), 6);
+            for (b, value) in row.iter().enumerate() {
+                assert_eq!(
+                    *value,
+                    p.evaluate_at(x_domain.element(a), y_domain.element(b))
+                );
+            }
+        }
+
+        let q = BivariatePolynomial::from_evaluations(evaluations, &x_domain, &y_domain).unwrap();
+        assert_eq!(q.dimensions(), (4, 6));
+        for (i, row) in q.coefficients().iter().enumerate() {
+            for (j, coefficient) in row.iter().enumerate() {
+                let expected = p
+                    .coefficients()
+                    .get(i)
+                    .and_then(|row| row.get(j))
+                    .copied()
+                    .unwrap_or_else(E::Fr::zero);
+                assert_eq!(*coefficient, expected);
+            }
+        }
+
+        assert_eq!(
+            BivariatePolynomial::from_evaluations(
+                vec![vec![E::Fr::one(); 6]; 3],
+                &x_domain,
+                &y_domain
+            ),
+            Err(Error::InvalidLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            BivariatePolynomial::from_evaluations(
+                vec![vec![E::Fr::one(); 5]; 4],
+                &x_domain,
+                &y_domain
+            ),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+    }
+
+    engine_test!(test_fft, fft);
+}
@@ -0,0 +1,250 @@
+//! Division with remainder of polynomials.
+
+use super::{trimmed, Error, Polynomial};
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Division switches from schoolbook to Newton iteration once both the divisor and the quotient
+/// have more coefficients than this.
+pub const FAST_DIVISION_THRESHOLD: usize = 64;
+
+impl<F: Field> Polynomial<F> {
+    /// Return `(q, r)` such that `self = q * divisor + r` and `deg(r) < deg(divisor)`.
+    /// Divisors of the form `a * (x - z)` use synthetic division, and large divisions
+    /// run in `O(M(n))` via [`Polynomial::inverse_series`].
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        let dividend = trimmed(&self.coefficients);
+        let divisor = trimmed(&divisor.coefficients);
+
+        if divisor.is_empty() {
+            return Err(Error::DivisionByZero);
+        }
+        if dividend.len() < divisor.len() {
+            return Ok((Self::zero(), Self::from_raw(dividend.to_vec())));
+        }
+
+        let quotient_length = dividend.len() - divisor.len() + 1;
+        let (quotient, remainder) = if divisor.len() == 2 {
+            divide_by_linear(dividend, divisor[0], divisor[1])
+        } else if divisor.len().min(quotient_length) > FAST_DIVISION_THRESHOLD {
+            fast_division(dividend, divisor)
+        } else {
+            long_division(dividend, divisor)
+        };
+        Ok((Self::from_raw(quotient), Self::from_raw(remainder)))
+    }
+
+    /// Return the power series inverse `g` with `self * g = 1 mod x^precision`,
+    /// or `None` if the constant term is zero.
+    pub fn inverse_series(&self, precision: usize) -> Option<Self> {
+        let constant_inverse = self.coefficients.first()?.inverse()?;
+
+        // Newton iteration `g <- g * (2 - f * g)` doubles the precision of `g` at each step.
+        let mut inverse = Self::constant(constant_inverse);
+        let mut current = 1;
+        while current < precision {
+            current = (2 * current).min(precision);
+            let f = Self::from_raw(self.coefficients.iter().take(current).copied().collect());
+            let error = truncate(&f * &inverse, current);
+            let correction = Self::constant(F::from_u64(2)) - error;
+            inverse = truncate(&inverse * &correction, current);
+        }
+        Some(truncate(inverse, precision))
+    }
+}
+
+/// Reduce `polynomial` modulo `x^length`.
+fn truncate<F: Field>(mut polynomial: Polynomial<F>, length: usize) -> Polynomial<F> {
+    polynomial.coefficients.truncate(length);
+    polynomial.truncate_leading_zeros();
+    polynomial
+}
+
+/// Divide via `rev(q) = rev(dividend) / rev(divisor) mod x^(deg(q) + 1)`, where `rev`
+/// reverses the coefficients, so the division becomes a power series inversion.
+fn fast_division<F: Field>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let quotient_length = dividend.len() - divisor.len() + 1;
+    let reversed_divisor = Polynomial::from_raw(divisor.iter().rev().copied().collect());
+    let divisor_inverse = reversed_divisor
+        .inverse_series(quotient_length)
+        .expect("leading coefficient is nonzero");
+    let reversed_dividend = Polynomial::from_raw(
+        dividend
+            .iter()
+            .rev()
+            .take(quotient_length)
+            .copied()
+            .collect(),
+    );
+
+    let mut quotient = (&reversed_dividend * &divisor_inverse).coefficients;
+    quotient.resize(quotient_length, F::zero());
+    quotient.reverse();
+
+    let product = &Polynomial::from_raw(divisor.to_vec()) * &Polynomial::from_raw(quotient.clone());
+    let remainder = dividend[..divisor.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, coefficient)| {
+            *coefficient - product.coefficients.get(i).copied().unwrap_or_else(F::zero)
+        })
+        .collect();
+    (quotient, remainder)
+}
+
+/// Divide by `a * x + b` with `a` nonzero, i.e. by `a * (x - z)` for `z = -b / a`.
+fn divide_by_linear<F: Field>(dividend: &[F], b: F, a: F) -> (Vec<F>, Vec<F>) {
+    let a_inverse = a.inverse().expect("leading coefficient is nonzero");
+    let z = -b * a_inverse;
+
+    // Synthetic division by `x - z`, from the highest-order coefficient down.
+    let mut quotient = vec![F::zero(); dividend.len() - 1];
+    let mut carry = F::zero();
+    for (i, coefficient) in dividend.iter().enumerate().skip(1).rev() {
+        carry = carry * z + *coefficient;
+        quotient[i - 1] = carry;
+    }
+    let remainder = carry * z + dividend[0];
+
+    for coefficient in quotient.iter_mut() {
+        *coefficient *= a_inverse;
+    }
+    (quotient, vec![remainder])
+}
+
+/// Schoolbook long division, `O(deg(dividend) * deg(divisor))`.
+fn long_division<F: Field>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut remainder = dividend.to_vec();
+    let divisor_degree = divisor.len() - 1;
+    let leading_inverse = divisor[divisor_degree]
+        .inverse()
+        .expect("leading coefficient is nonzero");
+
+    let mut quotient = vec![F::zero(); dividend.len() - divisor_degree];
+    for i in (0..quotient.len()).rev() {
+        let term_quotient = remainder[i + divisor_degree] * leading_inverse;
+        quotient[i] = term_quotient;
+        for (j, coefficient) in divisor.iter().enumerate() {
+            remainder[i + j] -= *coefficient * term_quotient;
+        }
+    }
+
+    remainder.truncate(divisor_degree);
+    (quotient, remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::polynomial::from_coefficients;
+    use crate::test_utils::{engine_test, polynomial};
+
+    fn division<E: PairingEngine>() {
+        let quotient = polynomial::<E::Fr>(&[3, 1, 4, 1, 5]);
+        let remainder = polynomial::<E::Fr>(&[9, 2]);
+
+        for divisor in [
+            polynomial::<E::Fr>(&[2, 7, 1]),
+            polynomial::<E::Fr>(&[6, 5, 0, 8]),
+            polynomial::<E::Fr>(&[7, 3]),
+            polynomial::<E::Fr>(&[7, 3, 0, 0]),
+            polynomial::<E::Fr>(&[5]),
+        ] {
+            let degree = divisor
+                .coefficients
+                .iter()
+                .rposition(|c| !c.is_zero())
+                .unwrap();
+            let remainder = from_coefficients(remainder.coefficients.iter().copied().take(degree));
+            let dividend = &quotient * &divisor + remainder.clone();
+            assert_eq!(
+                dividend.div_rem(&divisor),
+                Ok((quotient.clone(), remainder))
+            );
+        }
+    }
+
+    engine_test!(can_divide, division);
+
+    fn linear_division<E: PairingEngine>() {
+        let dividend = polynomial::<E::Fr>(&[1, 2, 3, 1, 1, 17, 32]);
+        let z = E::Fr::from_u64(1234);
+        let divisor = from_coefficients(vec![-z, E::Fr::one()].into_iter());
+
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(remainder.coefficients, vec![dividend.evaluate_at(z)]);
+        assert_eq!(
+            (
+                quotient.coefficients.clone(),
+                remainder.coefficients.clone()
+            ),
+            long_division(&dividend.coefficients, &divisor.coefficients)
+        );
+        assert_eq!(&quotient * &divisor + remainder, dividend);
+    }
+
+    engine_test!(can_divide_by_linear, linear_division);
+
+    fn degenerate_division<E: PairingEngine>() {
+        let a = polynomial::<E::Fr>(&[1, 2]);
+        let b = polynomial::<E::Fr>(&[1, 2, 3]);
+        let zero = Polynomial::<E::Fr>::zero();
+
+        assert_eq!(a.div_rem(&zero), Err(Error::DivisionByZero));
+        assert_eq!(a.div_rem(&polynomial(&[0, 0])), Err(Error::DivisionByZero));
+        assert_eq!(a.div_rem(&b), Ok((zero.clone(), a.clone())));
+        assert_eq!(zero.div_rem(&b), Ok((zero.clone(), zero.clone())));
+        assert_eq!(zero.div_rem(&a), Ok((zero.clone(), zero)));
+    }
+
+    fn series_inversion<E: PairingEngine>() {
+        let f = polynomial::<E::Fr>(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        for precision in [0, 1, 2, 5, 8, 13, 100] {
+            let g = f.inverse_series(precision).unwrap();
+            assert!(g.coefficients.len() <= precision);
+            let mut product = (&f * &g).coefficients;
+            product.truncate(precision);
+            let expected = if precision == 0 {
+                vec![]
+            } else {
+                vec![E::Fr::one()]
+            };
+            assert_eq!(trimmed(&product), &expected[..]);
+        }
+        assert_eq!(polynomial::<E::Fr>(&[0, 1]).inverse_series(4), None);
+        assert_eq!(Polynomial::<E::Fr>::zero().inverse_series(4), None);
+    }
+
+    engine_test!(can_invert_series, series_inversion);
+
+    fn fast_division_matches_long_division<E: PairingEngine>() {
+        let size = FAST_DIVISION_THRESHOLD as u64;
+        let dividend = from_coefficients((0..5 * size).map(|i| E::Fr::from_u64(i * i + 3)));
+        for divisor_length in [3, size + 1, 2 * size, 3 * size + 7, 4 * size - 2] {
+            // NOTE: a zero low-order coefficient exercises the trimming of reversed polynomials
+            let divisor = from_coefficients(
+                (0..divisor_length).map(|i| E::Fr::from_u64(if i == 0 { 0 } else { 7 * i + 1 })),
+            );
+            let (quotient, remainder) =
+                long_division(&dividend.coefficients, &divisor.coefficients);
+            let expected = (
+                from_coefficients(quotient.into_iter()),
+                from_coefficients(remainder.into_iter()),
+            );
+            let (quotient, remainder) =
+                fast_division(&dividend.coefficients, &divisor.coefficients);
+            let fast = (
+                from_coefficients(quotient.into_iter()),
+                from_coefficients(remainder.into_iter()),
+            );
+            assert_eq!(fast, expected);
+            assert_eq!(dividend.div_rem(&divisor), Ok(expected));
+        }
+    }
+
+    engine_test!(can_divide_fast, fast_division_matches_long_division);
+
+    engine_test!(can_divide_degenerate, degenerate_division);
+}
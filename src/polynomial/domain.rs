@@ -0,0 +1,634 @@
+//! Multiplicative subgroups of `F` used as evaluation domains, with (inverse) FFTs over them
+//! and their cosets, of field elements as well as of group elements.
+
+use super::six_step::{six_step_fft, SIX_STEP_FFT_THRESHOLD};
+use super::{powers_of, Error};
+use crate::engine::{Field, Group};
+use alloc::vec::Vec;
+use core::ops::{Add, Neg};
+use oblast::Fr;
+
+/// Values transformed by an FFT over `F`: elements of `F` itself, or points of a group over `F`.
+pub(super) trait FftValue<F: Field>:
+    Copy + Send + Sync + Add<Output = Self> + Neg<Output = Self>
+{
+    fn zero() -> Self;
+
+    fn scale(&self, scalar: F) -> Self;
+}
+
+impl<F: Field> FftValue<F> for F {
+    fn zero() -> Self {
+        F::zero()
+    }
+
+    fn scale(&self, scalar: F) -> Self {
+        *self * scalar
+    }
+}
+
+/// Wraps a group element, so that group FFTs share their implementation with field FFTs.
+#[derive(Debug, Clone, Copy)]
+struct Point<G>(G);
+
+impl<G: Add<Output = G>> Add for Point<G> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point(self.0 + rhs.0)
+    }
+}
+
+impl<G: Neg<Output = G>> Neg for Point<G> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point(-self.0)
+    }
+}
+
+impl<F: Field, G: Group<F>> FftValue<F> for Point<G> {
+    fn zero() -> Self {
+        Point(G::identity())
+    }
+
+    fn scale(&self, scalar: F) -> Self {
+        Point(self.0.mul_scalar(&scalar))
+    }
+}
+
+/// The subgroup `H = {1, w, w^2, ..., w^(n - 1)}` generated by a primitive `n`-th root of unity `w`,
+/// for any `n` dividing `r - 1`.
+///
+/// FFTs over `H` run in `O(n log n)` when `n` is of the form `2^a * 3^b`. Any other prime factor
+/// of `n` is handled by an `O(p^2)` discrete Fourier transform of that size, which is quadratic
+/// in the worst case where `n` is itself prime.
+///
+/// Power-of-two sizes are bounded by `2^TWO_ADICITY` (`2^32` for BLS12-381, `2^28` for BN254),
+/// and every size must fit in a `usize`, which excludes `2^32` itself on 32-bit targets.
+/// In practice memory is the limit: `new` eagerly stores two tables of `n / 2` twiddles
+/// (`n` for sizes which are not powers of two), and transforms hold `n` elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvaluationDomain<F: Field = Fr> {
+    size: usize,
+    size_inverse: F,
+    root_of_unity: F,
+    root_of_unity_inverse: F,
+    // NOTE: `w^i` (resp. `w^-i`) for `i < n / 2` when `n` is a power of two, as the other half
+    // are their negations, and for `i < n` otherwise, as the mixed-radix FFT needs all of them
+    twiddles: Vec<F>,
+    inverse_twiddles: Vec<F>,
+}
+
+impl<F: Field> EvaluationDomain<F> {
+    /// Construct the domain of the given `size`, which must divide `r - 1`.
+    pub fn new(size: usize) -> Result<Self, Error> {
+        let root_of_unity = subgroup_generator::<F>(size).ok_or(Error::InvalidDomainSize(size))?;
+        let root_of_unity_inverse = root_of_unity.inverse().expect("root of unity is nonzero");
+        let size_inverse = F::from_u64(size as u64)
+            .inverse()
+            .expect("size is less than the modulus");
+
+        let twiddle_count = if size.is_power_of_two() {
+            size / 2
+        } else {
+            size
+        };
+
+        Ok(Self {
+            size,
+            size_inverse,
+            root_of_unity,
+            root_of_unity_inverse,
+            twiddles: powers_of(root_of_unity, twiddle_count),
+            inverse_twiddles: powers_of(root_of_unity_inverse, twiddle_count),
+        })
+    }
+
+    /// Construct the smallest domain of power-of-two size with at least `size` elements.
+    pub fn with_minimum_size(size: usize) -> Result<Self, Error> {
+        let rounded_size = size
+            .max(1)
+            .checked_next_power_of_two()
+            .ok_or(Error::InvalidDomainSize(size))?;
+        Self::new(rounded_size)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn size_inverse(&self) -> F {
+        self.size_inverse
+    }
+
+    /// Return the generator `w` of the domain.
+    pub fn root_of_unity(&self) -> F {
+        self.root_of_unity
+    }
+
+    pub fn root_of_unity_inverse(&self) -> F {
+        self.root_of_unity_inverse
+    }
+
+    /// Return `w^index`.
+    pub fn element(&self, index: usize) -> F {
+        self.root_of_unity.pow((index % self.size) as u64)
+    }
+
+    /// Return the elements `1, w, ..., w^(n - 1)` of the domain in order.
+    pub fn elements(&self) -> Vec<F> {
+        powers_of(self.root_of_unity, self.size)
+    }
+
+    /// Iterate over the elements of the domain in order, reading them from the twiddles.
+    pub(super) fn cached_elements(&self) -> impl Iterator<Item = F> + '_ {
+        let half = self.twiddles.len();
+        (0..self.size).map(move |i| {
+            if let Some(twiddle) = self.twiddles.get(i) {
+                *twiddle
+            } else if i == 0 {
+                // NOTE: no twiddles are stored for the domain of size 1
+                F::one()
+            } else {
+                // only the first half is stored for power-of-two sizes, and `w^(n / 2) = -1`
+                -self.twiddles[i - half]
+            }
+        })
+    }
+
+    /// Convert the coefficients of a polynomial of degree less than `n` into
+    /// its evaluations over the domain, in-place. Shorter inputs are padded with zeros.
+    /// Panics if `values` holds more than `n` elements.
+    pub fn fft(&self, values: &mut Vec<F>) {
+        assert!(
+            values.len() <= self.size,
+            "cannot fit {} coefficients in a domain of size {}",
+            values.len(),
+            self.size
+        );
+        values.resize(self.size, F::zero());
+        self.transform(values, &self.twiddles);
+    }
+
+    /// Convert the evaluations of a polynomial over the domain into its coefficients, in-place.
+    /// Shorter inputs are padded with zeros.
+    /// Panics if `values` holds more than `n` elements.
+    pub fn ifft(&self, values: &mut Vec<F>) {
+        assert!(
+            values.len() <= self.size,
+            "cannot fit {} evaluations in a domain of size {}",
+            values.len(),
+            self.size
+        );
+        values.resize(self.size, F::zero());
+        self.transform(values, &self.inverse_twiddles);
+        for value in values.iter_mut() {
+            *value *= self.size_inverse;
+        }
+    }
+
+    /// Like [`EvaluationDomain::fft`], but evaluating over the coset `g * H` for `g`
+    /// the multiplicative generator of `F`.
+    pub fn coset_fft(&self, values: &mut Vec<F>) {
+        self.coset_fft_with_shift(values, F::multiplicative_generator());
+    }
+
+    /// Like [`EvaluationDomain::fft`], but evaluating over the coset `shift * H`.
+    pub fn coset_fft_with_shift(&self, values: &mut Vec<F>, shift: F) {
+        // NOTE: `f(shift * x)` has coefficients `shift^i * f_i`
+        scale_by_powers(values, shift);
+        self.fft(values);
+    }
+
+    /// Inverse of [`EvaluationDomain::coset_fft`].
+    pub fn coset_ifft(&self, values: &mut Vec<F>) {
+        self.coset_ifft_with_shift(values, F::multiplicative_generator());
+    }
+
+    /// Inverse of [`EvaluationDomain::coset_fft_with_shift`]. Panics if `shift` is zero.
+    pub fn coset_ifft_with_shift(&self, values: &mut Vec<F>, shift: F) {
+        self.ifft(values);
+        scale_by_powers(
+            values,
+            shift.inverse().expect("coset shift must be nonzero"),
+        );
+    }
+
+    /// Take the evaluations of a polynomial over this domain to its evaluations over the
+    /// domain `blowup` times larger, which contains this one as every `blowup`-th element.
+    /// `n * blowup` must divide `r - 1`.
+    pub fn extend(&self, evaluations: &[F], blowup: usize) -> Result<Vec<F>, Error> {
+        if evaluations.len() != self.size {
+            return Err(Error::InvalidLength {
+                expected: self.size,
+                actual: evaluations.len(),
+            });
+        }
+        let size = self
+            .size
+            .checked_mul(blowup)
+            .ok_or(Error::DomainSizeOverflow {
+                size: self.size,
+                blowup,
+            })?;
+        let extended_domain = Self::new(size)?;
+
+        let mut values = evaluations.to_vec();
+        self.ifft(&mut values);
+        extended_domain.fft(&mut values);
+        Ok(values)
+    }
+
+    /// Like [`EvaluationDomain::fft`], with points of `G` in place of the coefficients,
+    /// e.g. to move between the monomial and Lagrange bases of a setup.
+    /// The outputs are normalized with [`Group::batch_normalize`].
+    /// Panics if `values` holds more than `n` elements.
+    pub fn group_fft<G: Group<F>>(&self, values: &mut Vec<G>) {
+        self.group_transform(values, &self.twiddles);
+        G::batch_normalize(values);
+    }
+
+    /// Inverse of [`EvaluationDomain::group_fft`].
+    pub fn group_ifft<G: Group<F>>(&self, values: &mut Vec<G>) {
+        self.group_transform(values, &self.inverse_twiddles);
+        for value in values.iter_mut() {
+            *value = value.mul_scalar(&self.size_inverse);
+        }
+        G::batch_normalize(values);
+    }
+
+    fn group_transform<G: Group<F>>(&self, values: &mut Vec<G>, twiddles: &[F]) {
+        assert!(
+            values.len() <= self.size,
+            "cannot fit {} points in a domain of size {}",
+            values.len(),
+            self.size
+        );
+        values.resize(self.size, G::identity());
+        let mut points = values.iter().copied().map(Point).collect::<Vec<_>>();
+        self.transform(&mut points, twiddles);
+
+        values.clear();
+        values.extend(points.into_iter().map(|point| point.0));
+    }
+
+    fn transform<T: FftValue<F>>(&self, values: &mut Vec<T>, twiddles: &[F]) {
+        if self.size >= SIX_STEP_FFT_THRESHOLD && self.size.is_power_of_two() {
+            six_step_fft(values, twiddles);
+        } else if self.size.is_power_of_two() {
+            fft_in_place(values, twiddles);
+        } else {
+            *values = mixed_radix_fft(values, twiddles, 1);
+        }
+    }
+}
+
+/// Return a primitive `size`-th root of unity, or `None` if `size` does not divide `r - 1`.
+fn subgroup_generator<F: Field>(size: usize) -> Option<F> {
+    if size == 0 {
+        return None;
+    }
+    if size.is_power_of_two() {
+        if size.trailing_zeros() > F::TWO_ADICITY {
+            return None;
+        }
+        let mut root_of_unity = F::two_adic_root_of_unity();
+        for _ in size.trailing_zeros()..F::TWO_ADICITY {
+            root_of_unity *= root_of_unity;
+        }
+        return Some(root_of_unity);
+    }
+
+    // `g^((r - 1) / size)` for `g` a generator of the multiplicative group
+    let mut exponent = F::MODULUS;
+    exponent[0] -= 1;
+    let mut remainder = 0u128;
+    for limb in exponent.iter_mut().rev() {
+        let current = (remainder << 64) | *limb as u128;
+        *limb = (current / size as u128) as u64;
+        remainder = current % size as u128;
+    }
+    if remainder != 0 {
+        return None;
+    }
+    Some(F::multiplicative_generator().pow_limbs(&exponent))
+}
+
+/// Recursive mixed-radix Cooley-Tukey FFT of `values`, where `twiddles[i * stride]` ranges
+/// over the powers of a primitive `values.len()`-th root of unity.
+/// Splits off factors of 2 and 3, and computes any remaining size directly.
+fn mixed_radix_fft<F: Field, T: FftValue<F>>(
+    values: &[T],
+    twiddles: &[F],
+    stride: usize,
+) -> Vec<T> {
+    let n = values.len();
+    let twiddle = |exponent: usize| twiddles[(exponent % n) * stride];
+    let radix = match n {
+        1 => return values.to_vec(),
+        _ if n.is_multiple_of(2) => 2,
+        _ if n.is_multiple_of(3) => 3,
+        _ => {
+            // NOTE: the `O(n^2)` fallback for sizes with no factor of 2 or 3
+            return (0..n)
+                .map(|k| {
+                    values
+                        .iter()
+                        .enumerate()
+                        .fold(T::zero(), |sum, (j, x)| sum + x.scale(twiddle(j * k)))
+                })
+                .collect();
+        }
+    };
+
+    // Transform the `radix` interleaved subsequences, then combine them with
+    // `out[k] = sum_r w^(r * k) * sub_r[k mod m]`.
+    let m = n / radix;
+    let subtransforms = (0..radix)
+        .map(|r| {
+            let subsequence = values
+                .iter()
+                .skip(r)
+                .step_by(radix)
+                .copied()
+                .collect::<Vec<_>>();
+            mixed_radix_fft(&subsequence, twiddles, stride * radix)
+        })
+        .collect::<Vec<_>>();
+    (0..n)
+        .map(|k| {
+            subtransforms
+                .iter()
+                .enumerate()
+                .fold(T::zero(), |sum, (r, sub)| {
+                    sum + sub[k % m].scale(twiddle(r * k))
+                })
+        })
+        .collect()
+}
+
+/// Multiply `values[i]` by `x^i`.
+fn scale_by_powers<F: Field>(values: &mut [F], x: F) {
+    let mut power = F::one();
+    for value in values.iter_mut() {
+        *value *= power;
+        power *= x;
+    }
+}
+
+/// Iterative radix-2 Cooley-Tukey FFT, with `twiddles[i] = w^i` for (at least) `i < n / 2`.
+pub(super) fn fft_in_place<F: Field, T: FftValue<F>>(values: &mut [T], twiddles: &[F]) {
+    let n = values.len();
+    if n == 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        for chunk in values.chunks_mut(2 * half) {
+            let (low, high) = chunk.split_at_mut(half);
+            for (i, (x, y)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+                let t = y.scale(twiddles[i * stride]);
+                *y = *x + -t;
+                *x = *x + t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::polynomial::from_coefficients;
+    use crate::test_utils::engine_test;
+    use alloc::vec;
+
+    fn fft_matches_evaluation<E: PairingEngine>() {
+        for log_size in 0..6 {
+            let domain = EvaluationDomain::<E::Fr>::new(1 << log_size).unwrap();
+            let coefficients = (0..domain.size() as u64)
+                .map(|i| E::Fr::from_u64(i * i + 7))
+                .collect::<Vec<_>>();
+            let polynomial = from_coefficients(coefficients.clone().into_iter());
+
+            let mut values = coefficients.clone();
+            domain.fft(&mut values);
+            let expected = domain
+                .elements()
+                .into_iter()
+                .map(|x| polynomial.evaluate_at(x))
+                .collect::<Vec<_>>();
+            assert_eq!(values, expected);
+
+            domain.ifft(&mut values);
+            assert_eq!(values, coefficients);
+        }
+    }
+
+    engine_test!(test_fft, fft_matches_evaluation);
+
+    fn domain_parameters<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let w = domain.root_of_unity();
+        assert_eq!(w.pow(8), E::Fr::one());
+        assert_ne!(w.pow(4), E::Fr::one());
+        assert_eq!(w * domain.root_of_unity_inverse(), E::Fr::one());
+        assert_eq!(domain.size_inverse() * E::Fr::from_u64(8), E::Fr::one());
+        assert_eq!(domain.element(9), w);
+        assert_eq!(domain.elements().len(), 8);
+        for size in [1, 2, 8, 24] {
+            let domain = EvaluationDomain::<E::Fr>::new(size).unwrap();
+            assert_eq!(
+                domain.cached_elements().collect::<Vec<_>>(),
+                domain.elements()
+            );
+        }
+
+        // short inputs are padded
+        let mut values = vec![E::Fr::from_u64(3)];
+        domain.fft(&mut values);
+        assert_eq!(values, vec![E::Fr::from_u64(3); 8]);
+
+        assert_eq!(EvaluationDomain::<E::Fr>::with_minimum_size(5), Ok(domain));
+        assert_eq!(
+            EvaluationDomain::<E::Fr>::new(5),
+            Err(Error::InvalidDomainSize(5))
+        );
+        assert_eq!(
+            EvaluationDomain::<E::Fr>::new(0),
+            Err(Error::InvalidDomainSize(0))
+        );
+        let too_large = 1 << (E::Fr::TWO_ADICITY + 1);
+        assert_eq!(
+            EvaluationDomain::<E::Fr>::new(too_large),
+            Err(Error::InvalidDomainSize(too_large))
+        );
+    }
+
+    engine_test!(test_domain_parameters, domain_parameters);
+
+    fn mixed_radix_fft_matches_evaluation<E: PairingEngine>() {
+        // NOTE: 9 and 13 only divide `r - 1` for BN254, and 11 only for BLS12-381
+        let sizes = [3, 6, 9, 11, 12, 13, 22, 33, 48, 96, 144];
+        let domains = sizes
+            .iter()
+            .filter_map(|size| EvaluationDomain::<E::Fr>::new(*size).ok())
+            .collect::<Vec<_>>();
+        assert!(domains.len() >= 7);
+
+        for domain in domains {
+            let w = domain.root_of_unity();
+            assert_eq!(w.pow(domain.size() as u64), E::Fr::one());
+            for prime in [2, 3, 11, 13] {
+                if domain.size().is_multiple_of(prime) {
+                    assert_ne!(w.pow((domain.size() / prime) as u64), E::Fr::one());
+                }
+            }
+
+            let coefficients = (0..domain.size() as u64)
+                .map(|i| E::Fr::from_u64(i * i + 7))
+                .collect::<Vec<_>>();
+            let polynomial = from_coefficients(coefficients.clone().into_iter());
+            let mut values = coefficients.clone();
+            domain.fft(&mut values);
+            let expected = domain
+                .elements()
+                .into_iter()
+                .map(|x| polynomial.evaluate_at(x))
+                .collect::<Vec<_>>();
+            assert_eq!(values, expected);
+
+            domain.ifft(&mut values);
+            assert_eq!(values, coefficients);
+        }
+
+        // subgroups are nested, so the generators agree
+        let small = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let large = EvaluationDomain::<E::Fr>::new(24).unwrap();
+        assert_eq!(large.element(3), small.root_of_unity());
+    }
+
+    engine_test!(test_mixed_radix_fft, mixed_radix_fft_matches_evaluation);
+
+    fn group_fft_is_homomorphic<E: PairingEngine>() {
+        for size in [8, 6, 16] {
+            let domain = EvaluationDomain::<E::Fr>::new(size).unwrap();
+            let scalars = (0..size as u64 - 1)
+                .map(|i| E::Fr::from_u64(i * i + 2))
+                .collect::<Vec<_>>();
+            let mut evaluations = scalars.clone();
+            domain.fft(&mut evaluations);
+
+            let g1 = E::G1::generator();
+            let mut points = scalars.iter().map(|x| g1.mul_scalar(x)).collect::<Vec<_>>();
+            domain.group_fft(&mut points);
+            let expected = evaluations
+                .iter()
+                .map(|x| g1.mul_scalar(x))
+                .collect::<Vec<_>>();
+            assert_eq!(points, expected);
+            domain.group_ifft(&mut points);
+            let mut expected = scalars.iter().map(|x| g1.mul_scalar(x)).collect::<Vec<_>>();
+            expected.push(E::G1::identity());
+            assert_eq!(points, expected);
+
+            let g2 = E::G2::generator();
+            let mut points = scalars.iter().map(|x| g2.mul_scalar(x)).collect::<Vec<_>>();
+            domain.group_fft(&mut points);
+            let expected = evaluations
+                .iter()
+                .map(|x| g2.mul_scalar(x))
+                .collect::<Vec<_>>();
+            assert_eq!(points, expected);
+        }
+    }
+
+    engine_test!(test_group_fft, group_fft_is_homomorphic);
+
+    fn coset_fft_matches_evaluation<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(16).unwrap();
+        let coefficients = (0..13u64)
+            .map(|i| E::Fr::from_u64(3 * i + 1))
+            .collect::<Vec<_>>();
+        let polynomial = from_coefficients(coefficients.clone().into_iter());
+
+        for shift in [E::Fr::multiplicative_generator(), E::Fr::from_u64(5)] {
+            let mut values = coefficients.clone();
+            domain.coset_fft_with_shift(&mut values, shift);
+            let expected = domain
+                .elements()
+                .into_iter()
+                .map(|x| polynomial.evaluate_at(shift * x))
+                .collect::<Vec<_>>();
+            assert_eq!(values, expected);
+
+            domain.coset_ifft_with_shift(&mut values, shift);
+            values.truncate(coefficients.len());
+            assert_eq!(values, coefficients);
+        }
+
+        let mut values = coefficients.clone();
+        domain.coset_fft(&mut values);
+        let mut shifted = coefficients.clone();
+        domain.coset_fft_with_shift(&mut shifted, E::Fr::multiplicative_generator());
+        assert_eq!(values, shifted);
+        domain.coset_ifft(&mut values);
+        assert_eq!(&values[..coefficients.len()], &coefficients[..]);
+    }
+
+    engine_test!(test_coset_fft, coset_fft_matches_evaluation);
+
+    fn extend<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let polynomial = from_coefficients((0..8u64).map(|i| E::Fr::from_u64(i * i + 2)));
+        let mut evaluations = polynomial.coefficients.clone();
+        domain.fft(&mut evaluations);
+
+        for blowup in [1, 2, 3, 4] {
+            let extended = domain.extend(&evaluations, blowup).unwrap();
+            let extended_domain = EvaluationDomain::<E::Fr>::new(8 * blowup).unwrap();
+            let expected = extended_domain
+                .elements()
+                .into_iter()
+                .map(|x| polynomial.evaluate_at(x))
+                .collect::<Vec<_>>();
+            assert_eq!(extended, expected);
+            for (i, evaluation) in evaluations.iter().enumerate() {
+                assert_eq!(extended[i * blowup], *evaluation);
+            }
+        }
+
+        assert_eq!(
+            domain.extend(&evaluations, 5),
+            Err(Error::InvalidDomainSize(40))
+        );
+        assert_eq!(
+            domain.extend(&evaluations, usize::MAX),
+            Err(Error::DomainSizeOverflow {
+                size: 8,
+                blowup: usize::MAX
+            })
+        );
+        assert_eq!(
+            domain.extend(&evaluations[1..], 2),
+            Err(Error::InvalidLength {
+                expected: 8,
+                actual: 7
+            })
+        );
+    }
+
+    engine_test!(test_extend, extend);
+}
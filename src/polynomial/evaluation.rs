@@ -0,0 +1,120 @@
+//! Evaluation of a polynomial at many points, or of many polynomials at one point.
+
+use super::tree::SubproductTree;
+use super::{powers_of, Polynomial};
+use crate::engine::Field;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Multipoint evaluation falls back to Horner's method at or below this many points.
+pub const MULTIPOINT_EVALUATION_THRESHOLD: usize = 32;
+
+impl<F: Field> Polynomial<F> {
+    /// Evaluate the polynomial at every point in `points`, in order.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        self.evaluate_many_with_threshold(points, MULTIPOINT_EVALUATION_THRESHOLD)
+    }
+
+    /// Like [`Polynomial::evaluate_many`], reducing through a remainder tree until at most
+    /// `threshold` points remain below a node, which are then evaluated one at a time.
+    pub fn evaluate_many_with_threshold(&self, points: &[F], threshold: usize) -> Vec<F> {
+        if points.len() <= threshold || self.is_zero() {
+            return points.iter().map(|x| self.evaluate_at(*x)).collect();
+        }
+        SubproductTree::new(points).evaluate(self, threshold)
+    }
+}
+
+/// Evaluate each of `polynomials` at `z`, computing the powers of `z` only once.
+/// With the `parallel` feature, the polynomials are evaluated across threads.
+pub fn evaluate_batch<F: Field>(polynomials: &[Polynomial<F>], z: F) -> Vec<F> {
+    let length = polynomials
+        .iter()
+        .map(|p| p.coefficients.len())
+        .max()
+        .unwrap_or(0);
+    let powers = powers_of(z, length);
+    let evaluate = |polynomial: &Polynomial<F>| {
+        polynomial
+            .coefficients
+            .iter()
+            .zip(&powers)
+            .fold(F::zero(), |sum, (coefficient, power)| {
+                sum + *coefficient * *power
+            })
+    };
+
+    #[cfg(feature = "parallel")]
+    let evaluations = polynomials.par_iter().map(evaluate).collect();
+    #[cfg(not(feature = "parallel"))]
+    let evaluations = polynomials.iter().map(evaluate).collect();
+    evaluations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+
+    fn evaluate_many<E: PairingEngine>() {
+        let points = (0..100u64)
+            .map(|i| E::Fr::from_u64(i * 7 + 2))
+            .collect::<Vec<_>>();
+        for degree in [0, 5, 99, 250] {
+            let polynomial = Polynomial::from_raw(
+                (0..=degree as u64)
+                    .map(|i| E::Fr::from_u64(i * i + 1))
+                    .collect(),
+            );
+            let expected = points
+                .iter()
+                .map(|x| polynomial.evaluate_at(*x))
+                .collect::<Vec<_>>();
+            assert_eq!(polynomial.evaluate_many(&points), expected);
+            for threshold in [0, 1, 3, 64] {
+                assert_eq!(
+                    polynomial.evaluate_many_with_threshold(&points, threshold),
+                    expected
+                );
+            }
+        }
+        assert_eq!(
+            Polynomial::zero().evaluate_many(&points),
+            alloc::vec![E::Fr::zero(); points.len()]
+        );
+        assert!(Polynomial::constant(E::Fr::one())
+            .evaluate_many(&[])
+            .is_empty());
+    }
+
+    engine_test!(test_evaluate_many, evaluate_many);
+
+    fn evaluate_batch_at_one_point<E: PairingEngine>() {
+        let polynomials = (0..10u64)
+            .map(|i| {
+                Polynomial::from_raw(
+                    (0..i * 3)
+                        .map(|j| E::Fr::from_u64(i + j * j))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let z = E::Fr::from_u64(12345);
+        let expected = polynomials
+            .iter()
+            .map(|p| p.evaluate_at(z))
+            .collect::<Vec<_>>();
+        assert_eq!(evaluate_batch(&polynomials, z), expected);
+        assert!(evaluate_batch::<E::Fr>(&[], z).is_empty());
+
+        assert_eq!(
+            powers_of(z, 4),
+            alloc::vec![E::Fr::one(), z, z * z, z * z * z]
+        );
+        assert!(powers_of(z, 0).is_empty());
+    }
+
+    engine_test!(test_evaluate_batch, evaluate_batch_at_one_point);
+}
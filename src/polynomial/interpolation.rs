@@ -0,0 +1,150 @@
+//! Lagrange interpolation over arbitrary sets of points.
+
+use super::evaluation::MULTIPOINT_EVALUATION_THRESHOLD;
+use super::tree::SubproductTree;
+use super::{batch_inversion, Error, Polynomial};
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Interpolation switches from the quadratic algorithm to a subproduct tree above this many points.
+pub const INTERPOLATION_THRESHOLD: usize = 64;
+
+impl<F: Field> Polynomial<F> {
+    /// Return the unique polynomial of degree less than `points.len()` passing through every `(x, y)`.
+    /// Fails if two points share an x-coordinate.
+    pub fn interpolate(points: &[(F, F)]) -> Result<Self, Error> {
+        if points.is_empty() {
+            return Ok(Self::zero());
+        }
+        if points.len() <= INTERPOLATION_THRESHOLD {
+            interpolate_quadratic(points)
+        } else {
+            interpolate_with_tree(points)
+        }
+    }
+}
+
+/// Compute `I(x) = sum(y_i / Z'(x_i) * Z(x) / (x - x_i))` for `Z(x) = prod(x - x_i)` in `O(n^2)`.
+fn interpolate_quadratic<F: Field>(points: &[(F, F)]) -> Result<Polynomial<F>, Error> {
+    let mut vanishing = vec![F::one()];
+    for (x, _) in points {
+        // multiply by `x - x_i` in-place
+        vanishing.push(F::zero());
+        for i in (0..vanishing.len()).rev() {
+            let lower = if i > 0 { vanishing[i - 1] } else { F::zero() };
+            vanishing[i] = lower - *x * vanishing[i];
+        }
+    }
+
+    // NOTE: `Z'(x_i) = prod_{j != i} (x_i - x_j)`, which is zero exactly when `x_i` repeats
+    let mut weights = points
+        .iter()
+        .enumerate()
+        .map(|(i, (x_i, _))| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(F::one(), |product, (_, (x_j, _))| product * (*x_i - *x_j))
+        })
+        .collect::<Vec<_>>();
+    if weights.iter().any(|w| w.is_zero()) {
+        return Err(Error::DuplicatePoint);
+    }
+    batch_inversion(&mut weights);
+
+    let mut coefficients = vec![F::zero(); points.len()];
+    for ((x, y), weight) in points.iter().zip(weights) {
+        // synthetic division of `Z(x)` by `x - x_i`, accumulating into the result
+        let scale = *y * weight;
+        let mut carry = F::zero();
+        for k in (0..points.len()).rev() {
+            carry = carry * *x + vanishing[k + 1];
+            coefficients[k] += carry * scale;
+        }
+    }
+    Ok(Polynomial::from_raw(coefficients))
+}
+
+/// Compute the same interpolant via a subproduct tree, evaluating `Z'` at all `x_i` with the remainder tree.
+fn interpolate_with_tree<F: Field>(points: &[(F, F)]) -> Result<Polynomial<F>, Error> {
+    let xs = points.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+    let tree = SubproductTree::new(&xs);
+
+    let mut weights = tree.evaluate(&tree.root().derivative(), MULTIPOINT_EVALUATION_THRESHOLD);
+    if weights.iter().any(|w| w.is_zero()) {
+        return Err(Error::DuplicatePoint);
+    }
+    batch_inversion(&mut weights);
+    for (weight, (_, y)) in weights.iter_mut().zip(points) {
+        *weight *= *y;
+    }
+
+    Ok(tree.linear_combination(&weights))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+
+    fn interpolation<E: PairingEngine>() {
+        for count in [
+            1,
+            2,
+            5,
+            INTERPOLATION_THRESHOLD,
+            INTERPOLATION_THRESHOLD + 37,
+        ] {
+            let points = (0..count as u64)
+                .map(|i| (E::Fr::from_u64(i * i + 3), E::Fr::from_u64(i * 17 + 5)))
+                .collect::<Vec<_>>();
+            let polynomial = Polynomial::interpolate(&points).unwrap();
+            assert!(polynomial.degree().unwrap() < count);
+            for (x, y) in &points {
+                assert_eq!(polynomial.evaluate_at(*x), *y);
+            }
+        }
+        assert_eq!(
+            Polynomial::<E::Fr>::interpolate(&[]),
+            Ok(Polynomial::zero())
+        );
+    }
+
+    engine_test!(test_interpolation, interpolation);
+
+    fn recovers_polynomial<E: PairingEngine>() {
+        let polynomial = Polynomial::from_raw((0..90).map(|i| E::Fr::from_u64(i + 1)).collect());
+        let points = (0..90)
+            .map(|i| {
+                let x = -E::Fr::from_u64(i);
+                (x, polynomial.evaluate_at(x))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(Polynomial::interpolate(&points), Ok(polynomial.clone()));
+        assert_eq!(
+            Polynomial::interpolate(&points[..10]),
+            interpolate_quadratic(&points[..10])
+        );
+        assert_eq!(
+            interpolate_quadratic(&points),
+            interpolate_with_tree(&points)
+        );
+    }
+
+    engine_test!(test_recovers_polynomial, recovers_polynomial);
+
+    fn duplicate_points<E: PairingEngine>() {
+        for count in [3, INTERPOLATION_THRESHOLD + 1] {
+            let mut points = (0..count as u64)
+                .map(|i| (E::Fr::from_u64(i), E::Fr::from_u64(i)))
+                .collect::<Vec<_>>();
+            points[count - 1].0 = points[1].0;
+            assert_eq!(Polynomial::interpolate(&points), Err(Error::DuplicatePoint));
+        }
+    }
+
+    engine_test!(test_duplicate_points, duplicate_points);
+}
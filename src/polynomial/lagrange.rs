@@ -0,0 +1,212 @@
+//! Polynomials in evaluation form, i.e. in the Lagrange basis of an `EvaluationDomain`.
+
+use super::{batch_inversion, Error, EvaluationDomain, Polynomial};
+use crate::engine::Field;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use oblast::Fr;
+
+/// A polynomial of degree less than `n`, given by its evaluations over a domain of size `n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangePolynomial<'a, F: Field = Fr> {
+    // NOTE: `evaluations[i]` is the value at the `i`-th element `w^i` of the domain
+    evaluations: Vec<F>,
+    domain: &'a EvaluationDomain<F>,
+}
+
+impl<'a, F: Field> LagrangePolynomial<'a, F> {
+    /// Construct from exactly one evaluation per element of `domain`.
+    pub fn new(evaluations: Vec<F>, domain: &'a EvaluationDomain<F>) -> Result<Self, Error> {
+        if evaluations.len() != domain.size() {
+            return Err(Error::InvalidLength {
+                expected: domain.size(),
+                actual: evaluations.len(),
+            });
+        }
+        Ok(Self {
+            evaluations,
+            domain,
+        })
+    }
+
+    /// Evaluate `polynomial` over `domain`.
+    /// Fails if the degree is too large for the conversion to be lossless.
+    pub fn from_polynomial(
+        polynomial: &Polynomial<F>,
+        domain: &'a EvaluationDomain<F>,
+    ) -> Result<Self, Error> {
+        if polynomial.coefficients.len() > domain.size() {
+            return Err(Error::DegreeTooLarge {
+                degree: polynomial.coefficients.len() - 1,
+                size: domain.size(),
+            });
+        }
+        let mut evaluations = polynomial.coefficients.clone();
+        domain.fft(&mut evaluations);
+        Ok(Self {
+            evaluations,
+            domain,
+        })
+    }
+
+    /// Interpolate the coefficient form.
+    pub fn to_polynomial(&self) -> Polynomial<F> {
+        let mut coefficients = self.evaluations.clone();
+        self.domain.ifft(&mut coefficients);
+        Polynomial::from_raw(coefficients)
+    }
+
+    /// Return the evaluations, in the order of the elements of the domain.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    pub fn domain(&self) -> &'a EvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Evaluate at an arbitrary `point` in `O(n)` with the barycentric formula
+    /// `p(x) = (x^n - 1) / n * sum(p(w^i) * w^i / (x - w^i))`.
+    pub fn evaluate_at(&self, point: F) -> F {
+        let size = self.domain.size();
+
+        let mut denominators = self
+            .domain
+            .cached_elements()
+            .map(|x| point - x)
+            .collect::<Vec<_>>();
+        if let Some(i) = denominators.iter().position(|d| d.is_zero()) {
+            return self.evaluations[i];
+        }
+        batch_inversion(&mut denominators);
+
+        let sum = self
+            .evaluations
+            .iter()
+            .zip(self.domain.cached_elements().zip(denominators.iter()))
+            .fold(F::zero(), |sum, (value, (x, d))| sum + *value * x * *d);
+        let vanishing = point.pow(size as u64) - F::one();
+        sum * vanishing * self.domain.size_inverse()
+    }
+
+    fn assert_same_domain(&self, other: &Self) {
+        assert_eq!(
+            self.domain.size(),
+            other.domain.size(),
+            "polynomials must be over the same domain"
+        );
+    }
+}
+
+/// Pointwise addition. Panics if the domains differ.
+impl<'a, 'b, F: Field> Add<&'b LagrangePolynomial<'a, F>> for &'b LagrangePolynomial<'a, F> {
+    type Output = LagrangePolynomial<'a, F>;
+
+    fn add(self, rhs: &'b LagrangePolynomial<'a, F>) -> LagrangePolynomial<'a, F> {
+        self.assert_same_domain(rhs);
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(rhs.evaluations.iter())
+            .map(|(x, y)| *x + *y)
+            .collect();
+        LagrangePolynomial {
+            evaluations,
+            domain: self.domain,
+        }
+    }
+}
+
+/// Pointwise multiplication, i.e. the product modulo `x^n - 1`. Panics if the domains differ.
+impl<'a, 'b, F: Field> Mul<&'b LagrangePolynomial<'a, F>> for &'b LagrangePolynomial<'a, F> {
+    type Output = LagrangePolynomial<'a, F>;
+
+    fn mul(self, rhs: &'b LagrangePolynomial<'a, F>) -> LagrangePolynomial<'a, F> {
+        self.assert_same_domain(rhs);
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(rhs.evaluations.iter())
+            .map(|(x, y)| *x * *y)
+            .collect();
+        LagrangePolynomial {
+            evaluations,
+            domain: self.domain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::polynomial::from_coefficients;
+    use crate::test_utils::{engine_test, polynomial};
+    use alloc::vec;
+
+    fn conversion<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let p = polynomial::<E::Fr>(&[3, 1, 4, 1, 5, 9, 2, 6]);
+
+        let lagrange = LagrangePolynomial::from_polynomial(&p, &domain).unwrap();
+        assert_eq!(lagrange.evaluations().len(), 8);
+        assert_eq!(lagrange.evaluations()[1], p.evaluate_at(domain.element(1)));
+        assert_eq!(lagrange.to_polynomial(), p);
+
+        let roundtrip = LagrangePolynomial::new(lagrange.evaluations().to_vec(), &domain).unwrap();
+        assert_eq!(roundtrip, lagrange);
+
+        let too_large = polynomial::<E::Fr>(&[1; 9]);
+        assert_eq!(
+            LagrangePolynomial::from_polynomial(&too_large, &domain),
+            Err(Error::DegreeTooLarge { degree: 8, size: 8 })
+        );
+        assert_eq!(
+            LagrangePolynomial::new(vec![E::Fr::one(); 3], &domain),
+            Err(Error::InvalidLength {
+                expected: 8,
+                actual: 3
+            })
+        );
+    }
+
+    engine_test!(test_conversion, conversion);
+
+    fn barycentric_evaluation<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(16).unwrap();
+        let p = from_coefficients((0..13).map(|i| E::Fr::from_u64(i * 7 + 1)));
+        let lagrange = LagrangePolynomial::from_polynomial(&p, &domain).unwrap();
+
+        for point in [E::Fr::from_u64(1234), -E::Fr::from_u64(5), E::Fr::zero()] {
+            assert_eq!(lagrange.evaluate_at(point), p.evaluate_at(point));
+        }
+        // points in the domain are looked up directly
+        let point = domain.element(3);
+        assert_eq!(lagrange.evaluate_at(point), p.evaluate_at(point));
+    }
+
+    engine_test!(test_barycentric_evaluation, barycentric_evaluation);
+
+    fn pointwise_arithmetic<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let a = polynomial::<E::Fr>(&[1, 2, 3]);
+        let b = polynomial::<E::Fr>(&[4, 0, 5, 6]);
+        let a_lagrange = LagrangePolynomial::from_polynomial(&a, &domain).unwrap();
+        let b_lagrange = LagrangePolynomial::from_polynomial(&b, &domain).unwrap();
+
+        assert_eq!((&a_lagrange + &b_lagrange).to_polynomial(), &a + &b);
+        assert_eq!((&a_lagrange * &b_lagrange).to_polynomial(), &a * &b);
+    }
+
+    engine_test!(test_pointwise_arithmetic, pointwise_arithmetic);
+
+    #[test]
+    #[should_panic(expected = "same domain")]
+    fn test_mismatched_domains() {
+        let small = EvaluationDomain::<Fr>::new(2).unwrap();
+        let large = EvaluationDomain::<Fr>::new(4).unwrap();
+        let a = LagrangePolynomial::new(vec![Fr::one(); 2], &small).unwrap();
+        let b = LagrangePolynomial::new(vec![Fr::one(); 4], &large).unwrap();
+        let _ = &a + &b;
+    }
+}
@@ -0,0 +1,187 @@
+//! Multilinear polynomials, given by their evaluations over the boolean hypercube.
+
+use super::{Error, Polynomial};
+use crate::engine::Field;
+use alloc::vec::Vec;
+use oblast::Fr;
+
+/// The multilinear extension of a function `{0, 1}^k -> F`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultilinearPolynomial<F: Field = Fr> {
+    // NOTE: `evaluations[i]` is the value at the point whose `j`-th coordinate is bit `j` of `i`
+    evaluations: Vec<F>,
+    num_variables: usize,
+}
+
+impl<F: Field> MultilinearPolynomial<F> {
+    /// Construct from the `2^k` evaluations over `{0, 1}^k`.
+    pub fn new(evaluations: Vec<F>) -> Result<Self, Error> {
+        if !evaluations.len().is_power_of_two() {
+            return Err(Error::InvalidLength {
+                expected: evaluations.len().max(1).next_power_of_two(),
+                actual: evaluations.len(),
+            });
+        }
+        let num_variables = evaluations.len().trailing_zeros() as usize;
+        Ok(Self {
+            evaluations,
+            num_variables,
+        })
+    }
+
+    /// Return the number of variables `k`.
+    pub fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Return the evaluations over the hypercube.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Return the multilinear polynomial in the remaining `k - 1` variables obtained by
+    /// setting the first variable to `value`.
+    pub fn fix_variable(&self, value: F) -> Self {
+        self.partial_evaluate(&[value])
+    }
+
+    /// Return the multilinear polynomial in the remaining `k - values.len()` variables obtained
+    /// by setting the first variables to `values`. Panics if there are more values than variables.
+    pub fn partial_evaluate(&self, values: &[F]) -> Self {
+        assert!(
+            values.len() <= self.num_variables,
+            "cannot fix {} of {} variables",
+            values.len(),
+            self.num_variables
+        );
+        let mut evaluations = self.evaluations.clone();
+        for value in values {
+            // `f(v, x) = f(0, x) + v * (f(1, x) - f(0, x))`
+            let half = evaluations.len() / 2;
+            for i in 0..half {
+                let (low, high) = (evaluations[2 * i], evaluations[2 * i + 1]);
+                evaluations[i] = low + *value * (high - low);
+            }
+            evaluations.truncate(half);
+        }
+        Self {
+            evaluations,
+            num_variables: self.num_variables - values.len(),
+        }
+    }
+
+    /// Evaluate at `point` in `F^k`. Panics unless `point` has one coordinate per variable.
+    pub fn evaluate_at(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_variables,
+            "point must have one coordinate per variable"
+        );
+        self.partial_evaluate(point).evaluations[0]
+    }
+
+    /// Return the univariate polynomial whose coefficients are the evaluations over the hypercube.
+    pub fn to_univariate(&self) -> Polynomial<F> {
+        Polynomial::from_raw(self.evaluations.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+    use alloc::vec;
+
+    fn multilinear<F: Field>(count: u64) -> MultilinearPolynomial<F> {
+        MultilinearPolynomial::new((0..count).map(|i| F::from_u64(i * i + 3)).collect()).unwrap()
+    }
+
+    fn evaluation<E: PairingEngine>() {
+        let f = multilinear::<E::Fr>(8);
+        assert_eq!(f.num_variables(), 3);
+
+        // on the hypercube, the evaluations are looked up
+        for (i, expected) in f.evaluations().iter().enumerate() {
+            let point = (0..3)
+                .map(|j| E::Fr::from_u64((i as u64 >> j) & 1))
+                .collect::<Vec<_>>();
+            assert_eq!(f.evaluate_at(&point), *expected);
+        }
+
+        // elsewhere, `f(r) = sum_b eq(r, b) * f(b)`
+        let point = [E::Fr::from_u64(5), E::Fr::from_u64(11), -E::Fr::from_u64(2)];
+        let expected = f
+            .evaluations()
+            .iter()
+            .enumerate()
+            .fold(E::Fr::zero(), |sum, (i, value)| {
+                let eq = point
+                    .iter()
+                    .enumerate()
+                    .fold(E::Fr::one(), |product, (j, r)| {
+                        if (i >> j) & 1 == 1 {
+                            product * *r
+                        } else {
+                            product * (E::Fr::one() - *r)
+                        }
+                    });
+                sum + eq * *value
+            });
+        assert_eq!(f.evaluate_at(&point), expected);
+
+        let constant = MultilinearPolynomial::new(vec![E::Fr::from_u64(9)]).unwrap();
+        assert_eq!(constant.num_variables(), 0);
+        assert_eq!(constant.evaluate_at(&[]), E::Fr::from_u64(9));
+    }
+
+    engine_test!(test_evaluation, evaluation);
+
+    fn partial_evaluation<E: PairingEngine>() {
+        let f = multilinear::<E::Fr>(16);
+        let point = [
+            E::Fr::from_u64(3),
+            E::Fr::from_u64(1),
+            E::Fr::from_u64(4),
+            E::Fr::from_u64(15),
+        ];
+
+        let g = f.fix_variable(point[0]);
+        assert_eq!(g.num_variables(), 3);
+        assert_eq!(g.evaluate_at(&point[1..]), f.evaluate_at(&point));
+
+        let h = f.partial_evaluate(&point[..2]);
+        assert_eq!(h, g.fix_variable(point[1]));
+        assert_eq!(h.evaluate_at(&point[2..]), f.evaluate_at(&point));
+        assert_eq!(f.partial_evaluate(&[]), f);
+    }
+
+    engine_test!(test_partial_evaluation, partial_evaluation);
+
+    fn conversion<E: PairingEngine>() {
+        let f = multilinear::<E::Fr>(4);
+        assert_eq!(f.to_univariate().coefficients, f.evaluations());
+        assert_eq!(
+            MultilinearPolynomial::<E::Fr>::new(vec![E::Fr::one(); 6]),
+            Err(Error::InvalidLength {
+                expected: 8,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            MultilinearPolynomial::<E::Fr>::new(vec![]),
+            Err(Error::InvalidLength {
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+
+    engine_test!(test_conversion, conversion);
+
+    #[test]
+    #[should_panic(expected = "one coordinate per variable")]
+    fn test_evaluation_at_wrong_dimension() {
+        multilinear::<Fr>(4).evaluate_at(&[Fr::one()]);
+    }
+}
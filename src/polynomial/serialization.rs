@@ -0,0 +1,215 @@
+//! Canonical byte encodings of polynomials.
+//!
+//! Field elements are encoded as 32 big-endian bytes and must be less than the modulus.
+//! A `Polynomial` is prefixed by its number of coefficients as a big-endian `u64`, and the
+//! last coefficient must be nonzero so that each polynomial has a single encoding, while a
+//! `LagrangePolynomial` has a fixed size determined by its domain, like an EIP-4844 blob.
+
+use super::{Error, EvaluationDomain, LagrangePolynomial, Polynomial};
+use crate::engine::Field;
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+
+/// Length of the encoding of a single field element.
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+
+/// Length of the prefix holding the number of coefficients of an encoded `Polynomial`.
+const LENGTH_PREFIX_BYTES: usize = 8;
+
+fn encode_elements<F: Field>(elements: &[F], bytes: &mut Vec<u8>) {
+    for element in elements {
+        bytes.extend_from_slice(&element.to_bytes_be());
+    }
+}
+
+/// Decode `bytes`, whose length must be a multiple of `BYTES_PER_FIELD_ELEMENT`.
+fn decode_elements<F: Field>(bytes: &[u8]) -> Result<Vec<F>, Error> {
+    bytes
+        .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let chunk = chunk.try_into().expect("chunk has the right length");
+            F::from_bytes_be(chunk).ok_or(Error::NonCanonicalFieldElement { index })
+        })
+        .collect()
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Encode the number of coefficients, then each coefficient, lowest order first.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            LENGTH_PREFIX_BYTES + self.coefficients.len() * BYTES_PER_FIELD_ELEMENT,
+        );
+        bytes.extend_from_slice(&(self.coefficients.len() as u64).to_be_bytes());
+        encode_elements(&self.coefficients, &mut bytes);
+        bytes
+    }
+
+    /// Inverse of [`Polynomial::to_bytes`]. Encodings with a high-order zero coefficient
+    /// are rejected, as they are not canonical.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < LENGTH_PREFIX_BYTES {
+            return Err(Error::InvalidLength {
+                expected: LENGTH_PREFIX_BYTES,
+                actual: bytes.len(),
+            });
+        }
+        let (prefix, body) = bytes.split_at(LENGTH_PREFIX_BYTES);
+        let length = u64::from_be_bytes(prefix.try_into().expect("prefix has the right length"));
+        let expected = usize::try_from(length)
+            .ok()
+            .and_then(|length| length.checked_mul(BYTES_PER_FIELD_ELEMENT))
+            .and_then(|length| length.checked_add(LENGTH_PREFIX_BYTES))
+            .unwrap_or(usize::MAX);
+        if bytes.len() != expected {
+            return Err(Error::InvalidLength {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+        let coefficients = decode_elements::<F>(body)?;
+        if coefficients.last().is_some_and(F::is_zero) {
+            return Err(Error::NonCanonicalPolynomial);
+        }
+        Ok(Self::from_raw(coefficients))
+    }
+}
+
+impl<'a, F: Field> LagrangePolynomial<'a, F> {
+    /// Encode each evaluation in the order of the domain, without a length prefix.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.evaluations().len() * BYTES_PER_FIELD_ELEMENT);
+        encode_elements(self.evaluations(), &mut bytes);
+        bytes
+    }
+
+    /// Inverse of [`LagrangePolynomial::to_bytes`].
+    /// `bytes` must hold exactly one encoded evaluation per element of `domain`.
+    pub fn from_bytes(bytes: &[u8], domain: &'a EvaluationDomain<F>) -> Result<Self, Error> {
+        let expected = domain.size() * BYTES_PER_FIELD_ELEMENT;
+        if bytes.len() != expected {
+            return Err(Error::InvalidLength {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+        Self::new(decode_elements(bytes)?, domain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+    use alloc::vec;
+
+    fn polynomial_encoding<E: PairingEngine>() {
+        let polynomial = Polynomial::from_raw(vec![
+            E::Fr::from_u64(1),
+            -E::Fr::one(),
+            E::Fr::zero(),
+            E::Fr::from_u64(258),
+        ]);
+        let bytes = polynomial.to_bytes();
+        assert_eq!(bytes.len(), 8 + 4 * 32);
+        assert_eq!(bytes[..8], [0, 0, 0, 0, 0, 0, 0, 4]);
+        assert_eq!(bytes[8 + 3 * 32 + 30..], [1, 2]);
+        assert_eq!(Polynomial::from_bytes(&bytes), Ok(polynomial));
+
+        let zero = Polynomial::<E::Fr>::zero();
+        assert_eq!(zero.to_bytes(), vec![0; 8]);
+        assert_eq!(Polynomial::from_bytes(&zero.to_bytes()), Ok(zero));
+    }
+
+    engine_test!(test_polynomial_encoding, polynomial_encoding);
+
+    fn invalid_polynomial_encoding<E: PairingEngine>() {
+        let polynomial = Polynomial::from_raw(vec![E::Fr::from_u64(3), E::Fr::from_u64(5)]);
+        let bytes = polynomial.to_bytes();
+
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&bytes[..5]),
+            Err(Error::InvalidLength {
+                expected: 8,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidLength {
+                expected: 72,
+                actual: 71
+            })
+        );
+        let mut huge = bytes.clone();
+        huge[..8].copy_from_slice(&[0xff; 8]);
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&huge),
+            Err(Error::InvalidLength {
+                expected: usize::MAX,
+                actual: 72
+            })
+        );
+
+        let mut non_canonical = bytes.clone();
+        non_canonical[8 + 32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&non_canonical),
+            Err(Error::NonCanonicalFieldElement { index: 1 })
+        );
+
+        // `[2, 3, 0]` and `[1, 3]` would otherwise decode to the same polynomial
+        let mut padded = bytes[..8 + 32].to_vec();
+        padded[7] = 2;
+        padded.extend_from_slice(&[0; 32]);
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&padded),
+            Err(Error::NonCanonicalPolynomial)
+        );
+        let mut zero = vec![0; 8 + 32];
+        zero[7] = 1;
+        assert_eq!(
+            Polynomial::<E::Fr>::from_bytes(&zero),
+            Err(Error::NonCanonicalPolynomial)
+        );
+    }
+
+    engine_test!(
+        test_invalid_polynomial_encoding,
+        invalid_polynomial_encoding
+    );
+
+    fn evaluation_encoding<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let evaluations = (0..8u64)
+            .map(|i| E::Fr::from_u64(i * 7))
+            .collect::<Vec<_>>();
+        let lagrange = LagrangePolynomial::new(evaluations, &domain).unwrap();
+
+        let bytes = lagrange.to_bytes();
+        assert_eq!(bytes.len(), 8 * BYTES_PER_FIELD_ELEMENT);
+        assert_eq!(bytes[..32], [0; 32]);
+        assert_eq!(bytes[63], 7);
+        assert_eq!(
+            LagrangePolynomial::from_bytes(&bytes, &domain),
+            Ok(lagrange)
+        );
+
+        assert_eq!(
+            LagrangePolynomial::from_bytes(&bytes[32..], &domain),
+            Err(Error::InvalidLength {
+                expected: 256,
+                actual: 224
+            })
+        );
+        let mut non_canonical = bytes;
+        non_canonical[5 * 32..6 * 32].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            LagrangePolynomial::from_bytes(&non_canonical, &domain),
+            Err(Error::NonCanonicalFieldElement { index: 5 })
+        );
+    }
+
+    engine_test!(test_evaluation_encoding, evaluation_encoding);
+}
@@ -0,0 +1,181 @@
+//! Cache-efficient FFT for large power-of-two domains.
+//!
+//! The six-step algorithm views the `n = R * C` inputs as a matrix and replaces one FFT of
+//! size `n` with `C` FFTs of size `R` and `R` FFTs of size `C`, each over a contiguous row
+//! which fits in cache. With the `parallel` feature, rows are transformed across threads.
+
+use super::domain::{fft_in_place, FftValue};
+use crate::engine::Field;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Power-of-two FFTs switch from the iterative radix-2 algorithm to the six-step one at this size.
+pub const SIX_STEP_FFT_THRESHOLD: usize = 1 << 16;
+
+/// Side length of the square blocks moved together when transposing.
+const TRANSPOSE_BLOCK_SIZE: usize = 16;
+
+/// Six-step FFT of `values`, whose length is a power of two, with `twiddles[i] = w^i` for
+/// (at least) `i < n / 2`.
+/// The output is identical to that of `fft_in_place`.
+pub(super) fn six_step_fft<F: Field, T: FftValue<F>>(values: &mut Vec<T>, twiddles: &[F]) {
+    let n = values.len();
+    if n == 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+    let rows = 1 << log_n.div_ceil(2);
+    let columns = n / rows;
+
+    // With `j = C * j1 + j2` and `k = k1 + R * k2`, the transform factors as
+    // `X[k] = sum_j2 (w^R)^(j2 * k2) * w^(j2 * k1) * sum_j1 (w^C)^(j1 * k1) * x[j]`.
+    let row_twiddles = subsample(twiddles, columns, rows);
+    let column_twiddles = subsample(twiddles, rows, columns);
+
+    // NOTE: every transpose goes through this one buffer, which is then swapped with `values`
+    let mut scratch = values.clone();
+
+    // 1. transpose, so that each row holds `x[C * j1 + j2]` for a fixed `j2`
+    transpose(values, &mut scratch, rows, columns);
+    // 2. transform each of the `C` rows of length `R`
+    // 3. multiply by `w^(j2 * k1)`
+    for_each_row(values, rows, |j2, row| {
+        fft_in_place(row, &row_twiddles);
+        for (k1, value) in row.iter_mut().enumerate() {
+            *value = value.scale(twiddle(twiddles, j2 * k1, n));
+        }
+    });
+    // 4. transpose, so that each row holds the values for a fixed `k1`
+    transpose(values, &mut scratch, columns, rows);
+    // 5. transform each of the `R` rows of length `C`
+    for_each_row(values, columns, |_, row| {
+        fft_in_place(row, &column_twiddles)
+    });
+    // 6. transpose, placing `X[k1 + R * k2]` in order
+    transpose(values, &mut scratch, rows, columns);
+}
+
+/// Return `w^exponent` for `exponent < n`, using `w^(n / 2) = -1` past the first half.
+fn twiddle<F: Field>(twiddles: &[F], exponent: usize, n: usize) -> F {
+    if exponent < n / 2 {
+        twiddles[exponent]
+    } else {
+        -twiddles[exponent - n / 2]
+    }
+}
+
+/// Return `twiddles[0], twiddles[stride], ...`, the first half of the twiddles of a subdomain of `size`.
+fn subsample<F: Field>(twiddles: &[F], stride: usize, size: usize) -> Vec<F> {
+    twiddles
+        .iter()
+        .step_by(stride)
+        .take(size / 2)
+        .copied()
+        .collect()
+}
+
+/// Replace the `rows` by `columns` row-major matrix `values` with its transpose,
+/// using `scratch` (of the same length) as the destination before swapping the two.
+fn transpose<T: Copy>(values: &mut Vec<T>, scratch: &mut Vec<T>, rows: usize, columns: usize) {
+    for row_block in (0..rows).step_by(TRANSPOSE_BLOCK_SIZE) {
+        for column_block in (0..columns).step_by(TRANSPOSE_BLOCK_SIZE) {
+            for i in row_block..rows.min(row_block + TRANSPOSE_BLOCK_SIZE) {
+                for j in column_block..columns.min(column_block + TRANSPOSE_BLOCK_SIZE) {
+                    scratch[j * rows + i] = values[i * columns + j];
+                }
+            }
+        }
+    }
+    core::mem::swap(values, scratch);
+}
+
+/// Apply `f` to each `(index, row)` of `values` split into rows of `length`.
+#[cfg(feature = "parallel")]
+fn for_each_row<T: Send>(values: &mut [T], length: usize, f: impl Fn(usize, &mut [T]) + Sync) {
+    values
+        .par_chunks_mut(length)
+        .enumerate()
+        .for_each(|(index, row)| f(index, row));
+}
+
+/// Apply `f` to each `(index, row)` of `values` split into rows of `length`.
+#[cfg(not(feature = "parallel"))]
+fn for_each_row<T>(values: &mut [T], length: usize, f: impl Fn(usize, &mut [T])) {
+    values
+        .chunks_mut(length)
+        .enumerate()
+        .for_each(|(index, row)| f(index, row));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::polynomial::EvaluationDomain;
+    use crate::test_utils::engine_test;
+    use alloc::vec;
+    use oblast::Fr;
+
+    fn matches_radix_2<E: PairingEngine>() {
+        for log_size in 0..11 {
+            let domain = EvaluationDomain::<E::Fr>::new(1 << log_size).unwrap();
+            let values = (0..domain.size() as u64)
+                .map(|i| E::Fr::from_u64(i * i + 3))
+                .collect::<Vec<_>>();
+            // NOTE: only the first half of the twiddles, as stored by the domain
+            let mut twiddles = domain.elements();
+            twiddles.truncate(domain.size() / 2);
+
+            let mut expected = values.clone();
+            fft_in_place(&mut expected, &twiddles);
+            let mut result = values.clone();
+            six_step_fft(&mut result, &twiddles);
+            assert_eq!(result, expected);
+
+            // also the inverse transform, up to scaling by `n`
+            let inverse_twiddles = twiddles
+                .iter()
+                .map(|x| x.inverse().unwrap())
+                .collect::<Vec<_>>();
+            six_step_fft(&mut result, &inverse_twiddles);
+            for (x, y) in result.iter().zip(&values) {
+                assert_eq!(*x, *y * E::Fr::from_u64(domain.size() as u64));
+            }
+        }
+    }
+
+    engine_test!(test_six_step_fft, matches_radix_2);
+
+    #[test]
+    fn test_domain_uses_six_step_fft() {
+        let domain = EvaluationDomain::<Fr>::new(SIX_STEP_FFT_THRESHOLD).unwrap();
+        let values = (0..domain.size() as u64)
+            .map(Fr::from_u64)
+            .collect::<Vec<_>>();
+        let mut expected = values.clone();
+        fft_in_place(&mut expected, &domain.elements());
+
+        let mut result = values.clone();
+        domain.fft(&mut result);
+        assert_eq!(result, expected);
+        domain.ifft(&mut result);
+        assert_eq!(result, values);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let values = (0..6).map(Fr::from_u64).collect::<Vec<_>>();
+        let mut scratch = vec![Fr::zero(); 6];
+        let mut transposed = values.clone();
+        transpose(&mut transposed, &mut scratch, 2, 3);
+        let expected = [0, 3, 1, 4, 2, 5]
+            .iter()
+            .copied()
+            .map(Fr::from_u64)
+            .collect::<Vec<_>>();
+        assert_eq!(transposed, expected);
+        transpose(&mut transposed, &mut scratch, 3, 2);
+        assert_eq!(transposed, values);
+    }
+}
@@ -0,0 +1,233 @@
+//! Polynomials with few nonzero terms, e.g. `x^n - 1` or selectors.
+
+use super::{Error, Polynomial, VanishingPolynomial};
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Mul;
+use oblast::Fr;
+
+/// A univariate polynomial stored as its nonzero terms `(degree, coefficient)`.
+///
+/// Terms are kept sorted by increasing degree, with distinct degrees and nonzero coefficients,
+/// so the zero polynomial has no terms at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePolynomial<F: Field = Fr> {
+    terms: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Construct a polynomial from `terms` in any order, summing terms of equal degree.
+    pub fn new(mut terms: Vec<(usize, F)>) -> Self {
+        terms.sort_by_key(|(degree, _)| *degree);
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+        for (degree, coefficient) in terms {
+            match merged.last_mut() {
+                Some((last_degree, sum)) if *last_degree == degree => *sum += coefficient,
+                _ => merged.push((degree, coefficient)),
+            }
+        }
+        merged.retain(|(_, coefficient)| !coefficient.is_zero());
+        Self { terms: merged }
+    }
+
+    pub fn zero() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    /// Return the nonzero terms `(degree, coefficient)`, lowest degree first.
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.terms
+    }
+
+    /// Return the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.last().map(|(degree, _)| *degree)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn evaluate_at(&self, point: F) -> F {
+        // NOTE: step from one power to the next, so the exponentiations cost `O(log(degree))` each
+        let mut power = F::one();
+        let mut exponent = 0;
+        let mut sum = F::zero();
+        for (degree, coefficient) in &self.terms {
+            power *= point.pow((degree - exponent) as u64);
+            exponent = *degree;
+            sum += *coefficient * power;
+        }
+        sum
+    }
+
+    /// Return the dense form of the polynomial.
+    pub fn to_dense(&self) -> Polynomial<F> {
+        let mut coefficients = vec![F::zero(); self.degree().map_or(0, |degree| degree + 1)];
+        for (degree, coefficient) in &self.terms {
+            coefficients[*degree] = *coefficient;
+        }
+        Polynomial::from_raw(coefficients)
+    }
+}
+
+impl<F: Field> Default for SparsePolynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Field> From<&Polynomial<F>> for SparsePolynomial<F> {
+    fn from(polynomial: &Polynomial<F>) -> Self {
+        let terms = polynomial
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(degree, coefficient)| (degree, *coefficient))
+            .collect();
+        Self { terms }
+    }
+}
+
+impl<F: Field> From<VanishingPolynomial<F>> for SparsePolynomial<F> {
+    fn from(vanishing: VanishingPolynomial<F>) -> Self {
+        Self::new(vec![
+            (0, -vanishing.constant()),
+            (vanishing.degree(), F::one()),
+        ])
+    }
+}
+
+fn mul<F: Field>(sparse: &SparsePolynomial<F>, dense: &Polynomial<F>) -> Polynomial<F> {
+    let (degree, dense_degree) = match (sparse.degree(), dense.degree()) {
+        (Some(degree), Some(dense_degree)) => (degree, dense_degree),
+        _ => return Polynomial::zero(),
+    };
+    let mut coefficients = vec![F::zero(); degree + dense_degree + 1];
+    for (shift, coefficient) in &sparse.terms {
+        for (i, x) in dense.coefficients.iter().enumerate() {
+            coefficients[shift + i] += *coefficient * *x;
+        }
+    }
+    Polynomial::from_raw(coefficients)
+}
+
+impl<'a, F: Field> Mul<&'a Polynomial<F>> for &'a SparsePolynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: &'a Polynomial<F>) -> Polynomial<F> {
+        mul(self, rhs)
+    }
+}
+
+impl<'a, F: Field> Mul<&'a SparsePolynomial<F>> for &'a Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, rhs: &'a SparsePolynomial<F>) -> Polynomial<F> {
+        mul(rhs, self)
+    }
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Like [`Polynomial::div_rem`], in time proportional to the number of terms of `divisor`
+    /// rather than its degree.
+    pub fn div_rem_sparse(&self, divisor: &SparsePolynomial<F>) -> Result<(Self, Self), Error> {
+        let (&(divisor_degree, leading), lower_terms) =
+            divisor.terms.split_last().ok_or(Error::DivisionByZero)?;
+        if self.coefficients.len() <= divisor_degree {
+            return Ok((Self::zero(), self.clone()));
+        }
+        let leading_inverse = leading.inverse().expect("terms are nonzero");
+
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_degree];
+        for i in (divisor_degree..remainder.len()).rev() {
+            let q = remainder[i] * leading_inverse;
+            quotient[i - divisor_degree] = q;
+            for (degree, coefficient) in lower_terms {
+                remainder[i - divisor_degree + degree] -= q * *coefficient;
+            }
+        }
+        remainder.truncate(divisor_degree);
+        Ok((Self::from_raw(quotient), Self::from_raw(remainder)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+
+    fn sparse<F: Field>(terms: &[(usize, u64)]) -> SparsePolynomial<F> {
+        SparsePolynomial::new(
+            terms
+                .iter()
+                .map(|(degree, coefficient)| (*degree, F::from_u64(*coefficient)))
+                .collect(),
+        )
+    }
+
+    fn representation<E: PairingEngine>() {
+        let p = sparse::<E::Fr>(&[(9, 2), (0, 1), (4, 0), (9, 1)]);
+        assert_eq!(p.terms(), &[(0, E::Fr::one()), (9, E::Fr::from_u64(3))][..]);
+        assert_eq!(p.degree(), Some(9));
+
+        let dense = p.to_dense();
+        assert_eq!(dense.coefficients.len(), 10);
+        assert_eq!(SparsePolynomial::from(&dense), p);
+        let x = E::Fr::from_u64(5);
+        assert_eq!(p.evaluate_at(x), dense.evaluate_at(x));
+
+        let zero = SparsePolynomial::<E::Fr>::zero();
+        assert!(zero.is_zero());
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.to_dense(), Polynomial::zero());
+        assert_eq!(zero, sparse(&[(3, 0)]));
+        assert_eq!(zero.evaluate_at(x), E::Fr::zero());
+
+        let vanishing = VanishingPolynomial::new(8, E::Fr::from_u64(2));
+        assert_eq!(
+            SparsePolynomial::from(vanishing).to_dense(),
+            vanishing.to_polynomial()
+        );
+    }
+
+    engine_test!(test_representation, representation);
+
+    fn dense_arithmetic<E: PairingEngine>() {
+        let dense = Polynomial::from_raw(
+            (0..30u64)
+                .map(|i| E::Fr::from_u64(i * i + 1))
+                .collect::<Vec<_>>(),
+        );
+        let divisors = [
+            sparse::<E::Fr>(&[(0, 7), (16, 1)]),
+            sparse(&[(3, 2), (7, 5), (12, 3)]),
+            sparse(&[(0, 4)]),
+            sparse(&[(40, 1)]),
+        ];
+        for divisor in &divisors {
+            let product = divisor * &dense;
+            assert_eq!(product, &divisor.to_dense() * &dense);
+            assert_eq!(&dense * divisor, product);
+
+            assert_eq!(
+                dense.div_rem_sparse(divisor),
+                dense.div_rem(&divisor.to_dense())
+            );
+            assert_eq!(
+                product.div_rem_sparse(divisor),
+                Ok((dense.clone(), Polynomial::zero()))
+            );
+        }
+
+        let zero = SparsePolynomial::zero();
+        assert_eq!(&zero * &dense, Polynomial::zero());
+        assert_eq!(dense.div_rem_sparse(&zero), Err(Error::DivisionByZero));
+    }
+
+    engine_test!(test_dense_arithmetic, dense_arithmetic);
+}
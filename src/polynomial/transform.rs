@@ -0,0 +1,111 @@
+//! Transformations of a polynomial into related polynomials.
+
+use super::Polynomial;
+use crate::engine::Field;
+use alloc::vec;
+
+impl<F: Field> Polynomial<F> {
+    /// Return the formal derivative `p'(x)`.
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from_u64(i as u64) * *c)
+            .collect();
+        Self::from_raw(coefficients)
+    }
+
+    /// Return the composition `p(q(x))`.
+    pub fn compose(&self, other: &Self) -> Self {
+        // Horner's rule, with polynomials in place of field elements.
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Self::zero(), |result, coefficient| {
+                &result * other + Self::constant(*coefficient)
+            })
+    }
+
+    /// Return `p(c * x)`, i.e. scale the `i`-th coefficient by `c^i`.
+    pub fn scale_variable(&self, c: F) -> Self {
+        let mut power = F::one();
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|coefficient| {
+                let scaled = *coefficient * power;
+                power *= c;
+                scaled
+            })
+            .collect();
+        Self::from_raw(coefficients)
+    }
+
+    /// Return the reciprocal polynomial `x^d * p(1 / x)` for `d` the degree of `p`,
+    /// i.e. the coefficients in reverse order.
+    pub fn reverse(&self) -> Self {
+        Self::from_raw(self.coefficients.iter().rev().copied().collect())
+    }
+
+    /// Return `x^k * p(x)`.
+    pub fn shift_degree(&self, k: usize) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let mut coefficients = vec![F::zero(); k + self.coefficients.len()];
+        coefficients[k..].copy_from_slice(&self.coefficients);
+        Self::from_raw(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::{engine_test, polynomial};
+
+    fn transformations<E: PairingEngine>() {
+        let p = polynomial::<E::Fr>(&[5, 0, 3, 2]);
+        let q = polynomial::<E::Fr>(&[1, 4]);
+        let x = E::Fr::from_u64(7);
+
+        assert_eq!(p.derivative(), polynomial(&[0, 6, 6]));
+        assert_eq!(Polynomial::constant(x).derivative(), Polynomial::zero());
+
+        let composed = p.compose(&q);
+        assert_eq!(composed.degree(), Some(3));
+        assert_eq!(composed.evaluate_at(x), p.evaluate_at(q.evaluate_at(x)));
+        assert_eq!(Polynomial::zero().compose(&q), Polynomial::zero());
+        assert_eq!(
+            p.compose(&Polynomial::zero()),
+            Polynomial::constant(p.coefficients[0])
+        );
+
+        let c = E::Fr::from_u64(3);
+        assert_eq!(p.scale_variable(c).evaluate_at(x), p.evaluate_at(c * x));
+        assert_eq!(p.scale_variable(c), p.compose(&polynomial(&[0, 3])));
+
+        assert_eq!(p.reverse(), polynomial(&[2, 3, 0, 5]));
+        assert_eq!(
+            p.reverse().evaluate_at(x),
+            x.pow(3) * p.evaluate_at(x.inverse().unwrap())
+        );
+        // low-order zeros become high-order zeros and are dropped
+        assert_eq!(
+            polynomial::<E::Fr>(&[0, 0, 1, 2]).reverse(),
+            polynomial(&[2, 1])
+        );
+
+        assert_eq!(p.shift_degree(2), polynomial(&[0, 0, 5, 0, 3, 2]));
+        assert_eq!(p.shift_degree(2), &p * &polynomial(&[0, 0, 1]));
+        assert_eq!(p.shift_degree(0), p);
+        assert_eq!(
+            Polynomial::<E::Fr>::zero().shift_degree(3),
+            Polynomial::zero()
+        );
+    }
+
+    engine_test!(test_transformations, transformations);
+}
@@ -0,0 +1,94 @@
+//! Subproduct trees over a set of points, the basis of fast interpolation and multipoint evaluation.
+
+use super::Polynomial;
+use crate::engine::Field;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Binary tree whose nodes are the products of `x - x_i` over the points below them.
+pub(crate) struct SubproductTree<F: Field> {
+    points: Vec<F>,
+    // NOTE: `layers[0]` holds the linear factors `x - x_i`, and the last layer holds only their product
+    layers: Vec<Vec<Polynomial<F>>>,
+}
+
+impl<F: Field> SubproductTree<F> {
+    /// Build the tree over `points`, which must be non-empty.
+    pub(crate) fn new(points: &[F]) -> Self {
+        assert!(!points.is_empty(), "tree needs at least one point");
+        let leaves = points
+            .iter()
+            .map(|x| Polynomial::from_raw(vec![-*x, F::one()]))
+            .collect::<Vec<_>>();
+
+        let mut layers = vec![leaves];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self {
+            points: points.to_vec(),
+            layers,
+        }
+    }
+
+    /// Return the product of all `x - x_i`.
+    pub(crate) fn root(&self) -> &Polynomial<F> {
+        &self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Evaluate `polynomial` at every point by reducing it modulo each node, top-down.
+    /// Once a node spans at most `threshold` points, its remainder is evaluated directly.
+    pub(crate) fn evaluate(&self, polynomial: &Polynomial<F>, threshold: usize) -> Vec<F> {
+        // NOTE: the nodes of `layers[level]` span consecutive runs of `2^level` points
+        let mut level = self.layers.len() - 1;
+        let mut remainders = vec![remainder(polynomial, self.root())];
+        while level > 0 && 1 << level > threshold {
+            level -= 1;
+            remainders = self.layers[level]
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainder(&remainders[i / 2], node))
+                .collect();
+        }
+        self.points
+            .chunks(1 << level)
+            .zip(&remainders)
+            .flat_map(|(points, r)| points.iter().map(move |x| r.evaluate_at(*x)))
+            .collect()
+    }
+
+    /// Return `sum(weights[i] * root / (x - x_i))`, combining sibling subtrees bottom-up.
+    pub(crate) fn linear_combination(&self, weights: &[F]) -> Polynomial<F> {
+        let mut combination = weights
+            .iter()
+            .map(|w| Polynomial::constant(*w))
+            .collect::<Vec<_>>();
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            combination = combination
+                .chunks(2)
+                .zip(layer.chunks(2))
+                .map(|(values, nodes)| match (values, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        left * right_node + right * left_node
+                    }
+                    ([single], [_]) => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        combination.pop().expect("tree is non-empty")
+    }
+}
+
+fn remainder<F: Field>(dividend: &Polynomial<F>, divisor: &Polynomial<F>) -> Polynomial<F> {
+    let (_, remainder) = dividend.div_rem(divisor).expect("nodes are monic");
+    remainder
+}
@@ -0,0 +1,156 @@
+//! Vanishing polynomials `Z(x)`, which are zero exactly on a given set of points.
+
+use super::tree::SubproductTree;
+use super::{EvaluationDomain, Polynomial};
+use crate::engine::Field;
+use alloc::vec;
+use oblast::Fr;
+
+/// The sparse polynomial `x^n - c`, which vanishes on the coset `h * H` of a domain `H` of size `n`
+/// for `c = h^n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VanishingPolynomial<F: Field = Fr> {
+    degree: usize,
+    constant: F,
+}
+
+impl<F: Field> VanishingPolynomial<F> {
+    /// Construct `x^degree - constant`. Panics if `degree` is zero.
+    pub fn new(degree: usize, constant: F) -> Self {
+        assert!(degree > 0, "vanishing polynomial must have positive degree");
+        Self { degree, constant }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Return `c` in `x^n - c`.
+    pub fn constant(&self) -> F {
+        self.constant
+    }
+
+    pub fn evaluate_at(&self, point: F) -> F {
+        point.pow(self.degree as u64) - self.constant
+    }
+
+    /// Return the dense form of the polynomial.
+    pub fn to_polynomial(&self) -> Polynomial<F> {
+        let mut coefficients = vec![F::zero(); self.degree + 1];
+        coefficients[0] = -self.constant;
+        coefficients[self.degree] = F::one();
+        Polynomial::from_raw(coefficients)
+    }
+}
+
+impl<F: Field> Polynomial<F> {
+    /// Return `prod(x - z_i)` over `points`, built with a product tree.
+    pub fn vanishing(points: &[F]) -> Self {
+        if points.is_empty() {
+            return Self::constant(F::one());
+        }
+        let tree = SubproductTree::new(points);
+        tree.root().clone()
+    }
+
+    /// Return `x^n - 1`, the polynomial vanishing on every element of `domain`.
+    pub fn vanishing_for_domain(domain: &EvaluationDomain<F>) -> VanishingPolynomial<F> {
+        VanishingPolynomial::new(domain.size(), F::one())
+    }
+
+    /// Return `x^n - h^n`, the polynomial vanishing on the coset `shift * domain`.
+    pub fn vanishing_for_coset(domain: &EvaluationDomain<F>, shift: F) -> VanishingPolynomial<F> {
+        VanishingPolynomial::new(domain.size(), shift.pow(domain.size() as u64))
+    }
+
+    /// Return the quotient and remainder of dividing by `x^n - c`, in time linear in the degree.
+    pub fn divide_by_vanishing(&self, vanishing: &VanishingPolynomial<F>) -> (Self, Self) {
+        let n = vanishing.degree;
+        if self.coefficients.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+
+        // NOTE: `x^n = c` modulo the divisor, so each coefficient at `i >= n` folds into `i - n`
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - n];
+        for i in (n..remainder.len()).rev() {
+            let coefficient = remainder[i];
+            quotient[i - n] = coefficient;
+            remainder[i - n] += vanishing.constant * coefficient;
+        }
+        remainder.truncate(n);
+        (Self::from_raw(quotient), Self::from_raw(remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::PairingEngine;
+    use crate::test_utils::engine_test;
+    use alloc::vec::Vec;
+
+    fn vanishing<E: PairingEngine>() {
+        let points = (0..70u64)
+            .map(|i| E::Fr::from_u64(3 * i + 1))
+            .collect::<Vec<_>>();
+        let vanishing = Polynomial::vanishing(&points);
+        assert_eq!(vanishing.degree(), Some(points.len()));
+        assert_eq!(vanishing.coefficients.last(), Some(&E::Fr::one()));
+        for point in &points {
+            assert!(vanishing.evaluate_at(*point).is_zero());
+        }
+        assert!(!vanishing.evaluate_at(E::Fr::zero()).is_zero());
+        assert_eq!(
+            Polynomial::vanishing(&[]),
+            Polynomial::constant(E::Fr::one())
+        );
+    }
+
+    engine_test!(test_vanishing, vanishing);
+
+    fn vanishing_for_domain<E: PairingEngine>() {
+        let domain = EvaluationDomain::<E::Fr>::new(16).unwrap();
+        let vanishing = Polynomial::vanishing_for_domain(&domain);
+        assert_eq!(vanishing.degree(), 16);
+        for element in domain.elements() {
+            assert!(vanishing.evaluate_at(element).is_zero());
+        }
+        assert_eq!(
+            vanishing.to_polynomial(),
+            Polynomial::vanishing(&domain.elements())
+        );
+
+        let shift = E::Fr::multiplicative_generator();
+        let vanishing = Polynomial::vanishing_for_coset(&domain, shift);
+        for element in domain.elements() {
+            assert!(vanishing.evaluate_at(shift * element).is_zero());
+            assert!(!vanishing.evaluate_at(element).is_zero());
+        }
+    }
+
+    engine_test!(test_vanishing_for_domain, vanishing_for_domain);
+
+    fn divide_by_vanishing<E: PairingEngine>() {
+        let polynomial = Polynomial::from_raw(
+            (0..40u64)
+                .map(|i| E::Fr::from_u64(i * i + 5))
+                .collect::<Vec<_>>(),
+        );
+        for (degree, constant) in [(1, 1), (8, 1), (8, 9), (39, 2), (40, 3), (64, 1)] {
+            let vanishing = VanishingPolynomial::new(degree, E::Fr::from_u64(constant));
+            let expected = polynomial.div_rem(&vanishing.to_polynomial()).unwrap();
+            assert_eq!(polynomial.divide_by_vanishing(&vanishing), expected);
+        }
+
+        let domain = EvaluationDomain::<E::Fr>::new(8).unwrap();
+        let vanishing = Polynomial::vanishing_for_domain(&domain);
+        let multiple = &polynomial * &vanishing.to_polynomial();
+        assert_eq!(
+            multiple.divide_by_vanishing(&vanishing),
+            (polynomial, Polynomial::zero())
+        );
+    }
+
+    engine_test!(test_divide_by_vanishing, divide_by_vanishing);
+}
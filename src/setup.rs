@@ -1,77 +1,171 @@
-use num_bigint::BigUint;
-use oblast::{curve_order, Scalar, P1, P2};
+use crate::engine::{Bls12_381, Field, Group, PairingEngine};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use oblast::Fr;
+#[cfg(feature = "std")]
 use rand::prelude::*;
+pub use zeroize::Zeroizing;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct Setup {
-    pub in_g1: Vec<P1>,
-    pub in_g2: P2,
-}
+/// A secret field element, e.g. the trapdoor `s` of a `Setup`.
+/// The value is wiped from memory when dropped and is redacted from `Debug` output.
+pub struct SecretScalar<F: Field = Fr>(F);
 
-/// Generate a `Setup` with randomness supplied by the `rand` crate.
-/// Ensures the secret is properly constructed.
-pub fn generate_with_random_secret(degree: usize) -> Setup {
-    let mut rng = thread_rng();
+impl<F: Field> SecretScalar<F> {
+    pub fn new(value: F) -> Self {
+        Self(value)
+    }
 
-    let mut secret = [0u8; 32];
-    rng.fill_bytes(&mut secret);
+    /// Construct a secret from its canonical big-endian encoding.
+    /// Returns `None` if the value is not in the field.
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Option<Self> {
+        F::from_bytes_be(bytes).map(Self)
+    }
 
-    let mut s = BigUint::from_bytes_be(&secret);
+    pub fn expose_secret(&self) -> &F {
+        &self.0
+    }
+}
 
-    let modulus = curve_order();
-    while s >= modulus {
-        rng.fill_bytes(&mut secret);
-        s = BigUint::from_bytes_be(&secret);
+impl<F: Field> fmt::Debug for SecretScalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SecretScalar(..)")
     }
+}
 
-    generate(&secret, degree)
+impl<F: Field> Zeroize for SecretScalar<F> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
 }
 
-pub fn generate(secret: &[u8; 32], degree: usize) -> Setup {
-    let modulus = curve_order();
-    let s = BigUint::from_bytes_be(secret);
+impl<F: Field> Drop for SecretScalar<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
 
-    assert!(s < modulus, "secret must be less than size of group r");
+impl<F: Field> ZeroizeOnDrop for SecretScalar<F> {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setup<E: PairingEngine = Bls12_381> {
+    pub in_g1: Vec<E::G1>,
+    pub in_g2: E::G2,
+}
 
-    let mut points_in_g1 = vec![];
+impl<E: PairingEngine> Setup<E> {
+    /// Generate a `Setup` with randomness supplied by the `rand` crate.
+    /// Ensures the secret is properly constructed and wiped after use.
+    #[cfg(feature = "std")]
+    pub fn generate_with_random_secret(degree: usize) -> Self {
+        let mut rng = thread_rng();
 
-    let g1 = P1::generator();
-    for i in 0..=degree {
-        let i_as_bigint = BigUint::from_slice(&[i as u32]);
-        let s_i_as_bigint = s.modpow(&i_as_bigint, &modulus);
+        let mut secret = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(&mut secret[..]);
 
-        let mut s_i_bytes = vec![0u8; 32];
-        let raw_bytes = s_i_as_bigint.to_bytes_be();
-        s_i_bytes[32 - raw_bytes.len()..].copy_from_slice(&raw_bytes);
-        let s_i_scalar = Scalar::from_fr_bytes(&s_i_bytes);
+        let s = loop {
+            if let Some(s) = SecretScalar::from_bytes_be(&secret) {
+                break s;
+            }
+            rng.fill_bytes(&mut secret[..]);
+        };
 
-        let result = s_i_scalar * g1;
-        points_in_g1.push(result);
+        Self::from_secret(s, degree)
     }
 
-    // NOTE: `secret` in Fr via prior `assert`.
-    let scalar = Scalar::from_fr_bytes(secret);
-    let result_in_g2 = scalar * P2::generator();
+    /// Generate a `Setup` from the big-endian encoding of the trapdoor, which is wiped on return.
+    /// Panics if the secret is not in `Fr`.
+    /// See [`Setup::from_secret`] for the copies of the trapdoor which are not wiped.
+    pub fn generate(secret: Zeroizing<[u8; 32]>, degree: usize) -> Self {
+        let s =
+            SecretScalar::from_bytes_be(&secret).expect("secret must be less than size of group r");
+        Self::from_secret(s, degree)
+    }
 
-    Setup {
-        in_g1: points_in_g1,
-        in_g2: result_in_g2,
+    /// Generate a `Setup` from the trapdoor `s`.
+    ///
+    /// `secret` and every power of it computed here are wiped on return. Not wiped are any
+    /// copies of these `Copy` field elements the compiler makes in registers or on the stack,
+    /// e.g. to pass them to field multiplication, and the scalars used by the backend's
+    /// scalar multiplication: oblast (BLS12-381) wipes its `Scalar`, but arkworks (BN254)
+    /// converts the scalar to an unwiped big integer.
+    pub fn from_secret(secret: SecretScalar<E::Fr>, degree: usize) -> Self {
+        let mut points_in_g1 = vec![];
+
+        let g1 = E::G1::generator();
+        let mut s_i = SecretScalar::new(E::Fr::one());
+        for _ in 0..=degree {
+            let result = g1.mul_scalar(s_i.expose_secret());
+            points_in_g1.push(result);
+            s_i.0 *= secret.0;
+        }
+
+        let result_in_g2 = E::G2::generator().mul_scalar(secret.expose_secret());
+
+        Self {
+            in_g1: points_in_g1,
+            in_g2: result_in_g2,
+        }
     }
 }
 
+/// Generate a BLS12-381 `Setup` with randomness supplied by the `rand` crate.
+#[cfg(feature = "std")]
+pub fn generate_with_random_secret(degree: usize) -> Setup {
+    Setup::generate_with_random_secret(degree)
+}
+
+/// Generate a BLS12-381 `Setup` from the given `secret`, which is wiped on return.
+/// Panics if the secret is not in `Fr`.
+pub fn generate(secret: Zeroizing<[u8; 32]>, degree: usize) -> Setup {
+    Setup::generate(secret, degree)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::engine_test;
+    use alloc::format;
 
-    #[test]
-    fn test_generate() {
+    fn generate_deterministic<E: PairingEngine>() {
         let secret = [11u8; 32];
         let degree = 16;
 
-        let setup = generate(&secret, degree);
-        let second_setup = generate(&secret, degree);
+        let setup = Setup::<E>::generate(Zeroizing::new(secret), degree);
+        let second_setup = Setup::<E>::generate(Zeroizing::new(secret), degree);
         // NOTE: had an earlier bug w/ non-deterministic setups...
         assert_eq!(setup, second_setup);
         assert_eq!(setup.in_g1.len(), degree + 1);
     }
+
+    engine_test!(test_generate, generate_deterministic);
+
+    fn generate_from_secret<E: PairingEngine>() {
+        let secret = [11u8; 32];
+        let s = SecretScalar::<E::Fr>::from_bytes_be(&secret).unwrap();
+        assert_eq!(
+            Setup::<E>::from_secret(s, 4),
+            Setup::<E>::generate(Zeroizing::new(secret), 4)
+        );
+    }
+
+    engine_test!(test_generate_from_secret, generate_from_secret);
+
+    #[test]
+    fn test_secret_scalar_zeroize() {
+        let mut s = SecretScalar::new(Fr::from_u64(11));
+        assert_eq!(format!("{:?}", s), "SecretScalar(..)");
+        s.zeroize();
+        assert_eq!(*s.expose_secret(), Fr::zero());
+    }
+
+    #[test]
+    fn test_secret_scalar_from_bytes() {
+        // NOTE: on BLS12-381, this decodes through `oblast::Fr::from_bytes_be`
+        let bytes = [11u8; 32];
+        let secret = SecretScalar::<Fr>::from_bytes_be(&bytes).unwrap();
+        assert_eq!(secret.expose_secret().to_bytes_be(), bytes);
+        assert!(SecretScalar::<Fr>::from_bytes_be(&[0xff; 32]).is_none());
+    }
 }
@@ -0,0 +1,262 @@
+//! Generators for fuzzing (`arbitrary`) and property testing (`proptest`).
+//!
+//! Field elements are built through the `Field` trait so the generators work for every
+//! `PairingEngine`, including backends whose types do not implement the traits themselves.
+
+use crate::commitment::Opening;
+use crate::engine::{Field, Group, PairingEngine};
+use crate::polynomial::{self, Polynomial};
+use crate::setup::{Setup, Zeroizing};
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Unstructured};
+use proptest::prelude::*;
+
+/// The (public!) trapdoor used for every generated `Setup`.
+pub const SETUP_SECRET: [u8; 32] = {
+    let mut secret = [0u8; 32];
+    secret[31] = 11;
+    secret
+};
+
+/// Upper bound on the degree of a generated `Setup`, as each point costs a scalar multiplication.
+pub const MAX_SETUP_DEGREE: usize = 16;
+
+fn arbitrary_field_element<F: Field>(u: &mut Unstructured) -> arbitrary::Result<F> {
+    // NOTE: twice the width of the modulus so that the reduction is close to uniform.
+    let limbs = <[u64; 8]>::arbitrary(u)?;
+    Ok(F::from_limbs_reduced(&limbs))
+}
+
+/// Strategy for elements of `F`, mixing in small values which shrink well.
+pub fn field_element<F: Field>() -> impl Strategy<Value = F> {
+    prop_oneof![
+        any::<u64>().prop_map(F::from_u64),
+        any::<[u64; 8]>().prop_map(|limbs| F::from_limbs_reduced(&limbs)),
+    ]
+}
+
+/// Strategy for points of `G` as random multiples of the generator.
+pub fn group_element<F: Field, G: Group<F>>() -> impl Strategy<Value = G> {
+    field_element::<F>().prop_map(|x| G::generator().mul_scalar(&x))
+}
+
+/// Strategy for polynomials of degree at most `max_degree`, including the zero polynomial.
+pub fn polynomial<F: Field>(max_degree: usize) -> impl Strategy<Value = Polynomial<F>> {
+    prop::collection::vec(field_element::<F>(), 0..=max_degree + 1)
+        .prop_map(|coefficients| polynomial::from_coefficients(coefficients.into_iter()))
+}
+
+/// Strategy for setups from `SETUP_SECRET` with degree at most `max_degree`.
+pub fn setup<E: PairingEngine>(max_degree: usize) -> impl Strategy<Value = Setup<E>> {
+    (0..=max_degree).prop_map(|degree| Setup::generate(Zeroizing::new(SETUP_SECRET), degree))
+}
+
+/// Strategy for (most likely invalid) openings with a random value and proof.
+pub fn opening<E: PairingEngine>() -> impl Strategy<Value = Opening<E>> {
+    (field_element::<E::Fr>(), group_element::<E::Fr, E::G1>())
+        .prop_map(|(value, proof)| Opening { value, proof })
+}
+
+impl<'a, F: Field> Arbitrary<'a> for Polynomial<F> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let length = u.arbitrary_len::<[u64; 8]>()?;
+        let coefficients = (0..length)
+            .map(|_| arbitrary_field_element(u))
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        Ok(polynomial::from_coefficients(coefficients.into_iter()))
+    }
+}
+
+impl<'a, E: PairingEngine> Arbitrary<'a> for Setup<E> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let degree = u.int_in_range(0..=MAX_SETUP_DEGREE)?;
+        Ok(Setup::generate(Zeroizing::new(SETUP_SECRET), degree))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(core::mem::size_of::<usize>()))
+    }
+}
+
+impl<'a, E: PairingEngine> Arbitrary<'a> for Opening<E> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = arbitrary_field_element(u)?;
+        let proof = E::G1::generator().mul_scalar(&arbitrary_field_element(u)?);
+        Ok(Opening { value, proof })
+    }
+}
+
+impl<F: Field + 'static> proptest::arbitrary::Arbitrary for Polynomial<F> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        polynomial(MAX_SETUP_DEGREE).boxed()
+    }
+}
+
+impl<E: PairingEngine> proptest::arbitrary::Arbitrary for Setup<E> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        setup(MAX_SETUP_DEGREE).boxed()
+    }
+}
+
+impl<E: PairingEngine> proptest::arbitrary::Arbitrary for Opening<E> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        opening().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitment;
+    use crate::engine::Bls12_381;
+    #[cfg(feature = "bn254")]
+    use crate::engine::Bn254;
+    use crate::test_utils::engine_test;
+    use proptest::test_runner::{Config, TestRunner};
+
+    fn field_axioms<E: PairingEngine>() {
+        let element = field_element::<E::Fr>;
+        let mut runner = TestRunner::default();
+        runner
+            .run(&(element(), element(), element()), |(a, b, c)| {
+                prop_assert_eq!(a + b, b + a);
+                prop_assert_eq!(a * b, b * a);
+                prop_assert_eq!((a + b) + c, a + (b + c));
+                prop_assert_eq!((a * b) * c, a * (b * c));
+                prop_assert_eq!(a * (b + c), a * b + a * c);
+                prop_assert_eq!(a + E::Fr::zero(), a);
+                prop_assert_eq!(a * E::Fr::one(), a);
+                prop_assert_eq!(a + -a, E::Fr::zero());
+                prop_assert_eq!(a - b, a + -b);
+                match a.inverse() {
+                    Some(inverse) => prop_assert_eq!(b / a, b * inverse),
+                    None => prop_assert!(a.is_zero()),
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    engine_test!(test_field_axioms, field_axioms);
+
+    fn group_laws<F: Field, G: Group<F>>() {
+        let mut runner = TestRunner::new(Config::with_cases(32));
+        let points = (
+            group_element::<F, G>(),
+            group_element::<F, G>(),
+            group_element::<F, G>(),
+        );
+        let scalars = (field_element::<F>(), field_element::<F>());
+        runner
+            .run(&(points, scalars), |((p, q, r), (a, b))| {
+                prop_assert_eq!(p + q, q + p);
+                prop_assert_eq!((p + q) + r, p + (q + r));
+                prop_assert_eq!(p + G::identity(), p);
+                prop_assert_eq!(p + -p, G::identity());
+                prop_assert_eq!(p.mul_scalar(&(a + b)), p.mul_scalar(&a) + p.mul_scalar(&b));
+                prop_assert_eq!((p + q).mul_scalar(&a), p.mul_scalar(&a) + q.mul_scalar(&a));
+                prop_assert_eq!(p.mul_scalar(&(a * b)), p.mul_scalar(&a).mul_scalar(&b));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_group_laws() {
+        group_laws::<_, <Bls12_381 as PairingEngine>::G1>();
+        group_laws::<_, <Bls12_381 as PairingEngine>::G2>();
+        #[cfg(feature = "bn254")]
+        {
+            group_laws::<_, <Bn254 as PairingEngine>::G1>();
+            group_laws::<_, <Bn254 as PairingEngine>::G2>();
+        }
+    }
+
+    fn bilinearity<E: PairingEngine>() {
+        let mut runner = TestRunner::new(Config::with_cases(16));
+        let scalars = (field_element::<E::Fr>(), field_element::<E::Fr>());
+        runner
+            .run(&scalars, |(a, b)| {
+                let p = E::G1::generator();
+                let q = E::G2::generator();
+                let ab = a * b;
+                prop_assert!(E::verify_pairings(
+                    p.mul_scalar(&a),
+                    q.mul_scalar(&b),
+                    p.mul_scalar(&ab),
+                    q
+                ));
+                prop_assert!(E::verify_pairings(
+                    p.mul_scalar(&a),
+                    q.mul_scalar(&b),
+                    p,
+                    q.mul_scalar(&ab)
+                ));
+                prop_assert!(!E::verify_pairings(
+                    p.mul_scalar(&a),
+                    q.mul_scalar(&b),
+                    p.mul_scalar(&(ab + E::Fr::one())),
+                    q
+                ));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    engine_test!(test_bilinearity, bilinearity);
+
+    fn commit_open_verify<E: PairingEngine>() {
+        let mut runner = TestRunner::new(Config::with_cases(16));
+        let inputs = (0..=8usize)
+            .prop_flat_map(|degree| (Just(degree), polynomial::<E::Fr>(degree)))
+            .prop_flat_map(|(degree, polynomial)| {
+                (
+                    Just(Setup::<E>::generate(Zeroizing::new(SETUP_SECRET), degree)),
+                    Just(polynomial),
+                    field_element::<E::Fr>(),
+                    opening::<E>(),
+                )
+            });
+        runner
+            .run(&inputs, |(setup, polynomial, point, forged)| {
+                let commitment = commitment::create(&polynomial, &setup).unwrap();
+                let opening = commitment.open_at(point);
+                prop_assert_eq!(opening.value, polynomial.evaluate_at(point));
+                prop_assert!(opening.verify(&point, &commitment));
+                if forged.value != opening.value {
+                    prop_assert!(!forged.verify(&point, &commitment));
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    engine_test!(test_commit_open_verify, commit_open_verify);
+
+    #[test]
+    fn test_arbitrary() {
+        let bytes = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&bytes);
+        let setup = Setup::<Bls12_381>::arbitrary(&mut u).unwrap();
+        assert!(setup.in_g1.len() <= MAX_SETUP_DEGREE + 1);
+        let polynomial = Polynomial::arbitrary(&mut u).unwrap();
+        // NOTE: only commit to as many coefficients as the setup supports
+        let polynomial = polynomial::from_coefficients(
+            polynomial.coefficients.into_iter().take(setup.in_g1.len()),
+        );
+        let opening = Opening::<Bls12_381>::arbitrary(&mut u).unwrap();
+        let point = opening.value;
+        let _ = commitment::create(&polynomial, &setup)
+            .unwrap()
+            .open_at(point);
+    }
+}
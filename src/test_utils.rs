@@ -0,0 +1,24 @@
+//! Helpers shared by the unit tests.
+
+use crate::engine::Field;
+use crate::polynomial::{from_coefficients, Polynomial};
+
+/// Return the polynomial with the given small coefficients, lowest order first.
+pub(crate) fn polynomial<F: Field>(coefficients: &[u64]) -> Polynomial<F> {
+    from_coefficients(coefficients.iter().copied().map(F::from_u64))
+}
+
+/// Define the test `$name`, which runs the generic `$body::<E: PairingEngine>()` for every
+/// engine: BLS12-381, and BN254 with the `bn254` feature.
+macro_rules! engine_test {
+    ($name:ident, $body:ident) => {
+        #[test]
+        fn $name() {
+            $body::<$crate::engine::Bls12_381>();
+            #[cfg(feature = "bn254")]
+            $body::<$crate::engine::Bn254>();
+        }
+    };
+}
+
+pub(crate) use engine_test;